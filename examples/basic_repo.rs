@@ -2,11 +2,10 @@
 #![allow(clippy::extra_unused_lifetimes)]
 #![allow(clippy::needless_lifetimes)]
 
-use parking_lot::ArcMutexGuard;
 use sqlx::prelude::FromRow;
 use sqlx::{QueryBuilder, Transaction};
 use sqlx_utils::prelude::*;
-use std::sync::{Arc, LazyLock};
+use std::sync::LazyLock;
 use std::time::Duration;
 
 pub static DATABASE_URL: LazyLock<String> =
@@ -128,20 +127,11 @@ impl UserRepo {
     where
         'b: 'a,
     {
-        self.transaction_concurrent::<'a, 'b>([
-            |tx: Arc<parking_lot::Mutex<Transaction<'b, Database>>>| async move {
-                let mut tx = match tx.try_lock_arc() {
-                    Some(tx) => tx,
-                    None => return Err(Error::MutexLockError),
-                };
+        self.transaction_concurrent::<'a, 'b>([|tx: SharedTransaction<'b>| async move {
+            let mut tx = tx.lock().await?;
 
-                let res = USER_REPO.save_with_executor(&mut **tx, model).await;
-
-                ArcMutexGuard::<parking_lot::RawMutex, Transaction<'b, sqlx::Any>>::unlock_fair(tx);
-
-                res
-            },
-        ])
+            USER_REPO.save_with_executor(&mut **tx, model).await
+        }])
         .await
         .map_err(Into::into)
     }
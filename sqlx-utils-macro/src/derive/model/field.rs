@@ -0,0 +1,162 @@
+use proc_macro_error2::emit_error;
+use syn::ext::IdentExt;
+use syn::{Expr, Field, GenericArgument, Ident, LitStr, PathArguments, Type};
+
+/// A named field of a struct deriving `Model`.
+///
+/// Holds the options parsed from the `#[model(...)]` attribute on the field:
+///
+/// - `id`: The field is (part of) the primary key
+/// - `column = "name"`: The column name, defaults to the field name
+/// - `skip_insert`: The field is left out of the `INSERT` statement
+/// - `skip_update`: The field is left out of the `SET` clause of the `UPDATE` statement
+/// - `generated`: The key is assigned by the database and left out of the `INSERT` statement
+/// - `unsaved = expr`: The key value marking a model that has not been saved yet, the key is left
+///   out of the `INSERT` statement
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct ModelField {
+    pub(crate) ident: Ident,
+    pub(crate) ty: Type,
+    pub(crate) column: String,
+    /// The name `FromRow` reads the field from, the field name unless renamed with `#[sqlx(rename = "...")]`.
+    pub(crate) row_name: String,
+    /// The field has no column of its own, as sqlx skips it with `#[sqlx(skip)]` or reads it
+    /// from several columns with `#[sqlx(flatten)]`.
+    pub(crate) no_column: bool,
+    pub(crate) id: bool,
+    pub(crate) skip_insert: bool,
+    pub(crate) skip_update: bool,
+    pub(crate) generated: bool,
+    pub(crate) unsaved: Option<Expr>,
+}
+
+impl ModelField {
+    /// Parses a field, returns [`None`] if the field is not named.
+    pub(crate) fn new(field: &Field) -> Option<Self> {
+        let ident = field.ident.clone()?;
+
+        let mut model_field = Self {
+            column: ident.unraw().to_string(),
+            row_name: ident.unraw().to_string(),
+            no_column: false,
+            ident,
+            ty: field.ty.clone(),
+            id: false,
+            skip_insert: false,
+            skip_update: false,
+            generated: false,
+            unsaved: None,
+        };
+
+        for attr in &field.attrs {
+            if attr.path().is_ident("sqlx") {
+                // Only the rename and the fields without a column matter here, sqlx reports any
+                // other problems with its attribute.
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let name: LitStr = meta.value()?.parse()?;
+                        model_field.row_name = name.value();
+                    } else if meta.path.is_ident("skip") || meta.path.is_ident("flatten") {
+                        model_field.no_column = true;
+                    } else if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<Expr>()?;
+                    }
+
+                    Ok(())
+                });
+
+                continue;
+            }
+
+            if !attr.path().is_ident("model") {
+                continue;
+            }
+
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("id") {
+                    model_field.id = true;
+                } else if meta.path.is_ident("column") {
+                    let column: LitStr = meta.value()?.parse()?;
+                    model_field.column = column.value();
+                } else if meta.path.is_ident("skip_insert") {
+                    model_field.skip_insert = true;
+                } else if meta.path.is_ident("skip_update") {
+                    model_field.skip_update = true;
+                } else if meta.path.is_ident("generated") {
+                    model_field.generated = true;
+                } else if meta.path.is_ident("unsaved") {
+                    model_field.unsaved = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("Unknown model field attribute"));
+                }
+
+                Ok(())
+            });
+
+            if let Err(err) = result {
+                emit_error!(
+                    err.span(), "Failed to parse model attribute: {}", err;
+                    help = "Supported field attributes are: `id`, `column = \"...\"`, `skip_insert`, `skip_update`, `generated`, `unsaved = ...`"
+                );
+            }
+        }
+
+        Some(model_field)
+    }
+
+    /// Whether the field is part of the `INSERT` statement.
+    ///
+    /// Keys that can mark the model as unsaved are left to the database to assign.
+    #[inline]
+    pub(crate) fn is_insertable(&self) -> bool {
+        !self.skip_insert && !self.is_unsaved_key()
+    }
+
+    /// Whether the field is a key marking the model as unsaved, by being `generated`, holding its
+    /// `unsaved` sentinel or being declared as `Option<T>`.
+    #[inline]
+    pub(crate) fn is_unsaved_key(&self) -> bool {
+        self.id && (self.generated || self.unsaved.is_some() || self.option_inner().is_some())
+    }
+
+    /// Whether the field is part of the `SET` clause of the `UPDATE` statement.
+    #[inline]
+    pub(crate) fn is_updatable(&self) -> bool {
+        !self.id && !self.skip_update
+    }
+
+    /// The expression selecting the field's column, aliased to the name `FromRow` expects when
+    /// the two differ.
+    pub(crate) fn select_expr(&self) -> String {
+        if self.column == self.row_name {
+            self.column.clone()
+        } else {
+            format!("{} AS {}", self.column, self.row_name)
+        }
+    }
+
+    /// Gets the inner type `T` if the field is declared as `Option<T>`.
+    pub(crate) fn option_inner(&self) -> Option<&Type> {
+        let Type::Path(path) = &self.ty else {
+            return None;
+        };
+
+        if path.qself.is_some() {
+            return None;
+        }
+
+        let segment = path.path.segments.last()?;
+
+        if segment.ident != "Option" {
+            return None;
+        }
+
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
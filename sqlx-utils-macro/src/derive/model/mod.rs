@@ -1,7 +1,11 @@
+mod field;
+
+use crate::types::{crate_name, placeholder};
+use field::ModelField;
 use proc_macro_error2::{abort, emit_error};
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned, ToTokens};
-use syn::{Field, Fields, Generics, Ident, spanned::Spanned};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{Attribute, Generics, Ident, Index, LitStr, spanned::Spanned};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[allow(dead_code)]
@@ -9,7 +13,9 @@ pub struct DeriveModel {
     struct_sig: syn::DataStruct,
     name: Ident,
     generics: Generics,
-    id_field: Field,
+    table: Option<String>,
+    fields: Vec<ModelField>,
+    id_fields: Vec<usize>,
 }
 
 impl DeriveModel {
@@ -24,87 +30,400 @@ impl DeriveModel {
                 Err(quote_spanned! { span => compile_error!("Expected Struct but found Enum") })
             }
             syn::Data::Union(..) => {
-                Err(quote_spanned! { span => compile_error!("Expected Struct but found Enum") })
+                Err(quote_spanned! { span => compile_error!("Expected Struct but found Union") })
             }
         }?;
 
-        let id_field = Self::find_id_field(&struct_sig.fields).unwrap_or_else(|| {
+        let mut fields = struct_sig
+            .fields
+            .iter()
+            .map(|field| {
+                ModelField::new(field).unwrap_or_else(|| {
+                    abort!(
+                        field,
+                        "Model can only be derived for structs with named fields";
+                        help = "Use a struct with named fields, `struct User { id: i64 }`"
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for field in fields.iter().filter(|field| field.no_column) {
+            if field.id {
+                emit_error!(
+                    field.ident,
+                    "The id can not be a field without a column of its own";
+                    help = "Fields with `#[sqlx(skip)]` or `#[sqlx(flatten)]` are left out of every query"
+                );
+            }
+        }
+
+        // Skipped and flattened fields are not read from a column of their own
+        fields.retain(|field| !field.no_column);
+
+        let table = Self::parse_table(&derive_input.attrs);
+
+        let id_fields = Self::find_id_fields(&fields);
+
+        if id_fields.is_empty() {
             abort!(
                 name,
                 "No field marked with #[model(id)] and no field named 'id' found";
                 help = "Add #[model(id)] to the field that represents the model's ID"
             )
-        });
+        }
+
+        for &idx in &id_fields {
+            fields[idx].id = true;
+        }
+
+        for field in &fields {
+            if !field.id && (field.generated || field.unsaved.is_some()) {
+                emit_error!(
+                    field.ident,
+                    "`generated` and `unsaved` can only be used on the id field";
+                    help = "Add `id` to the attribute, `#[model(id, generated)]`"
+                );
+            } else if id_fields.len() > 1
+                && (field.generated || field.unsaved.is_some() || field.option_inner().is_some())
+            {
+                emit_error!(
+                    field.ident,
+                    "Composite keys can not be generated or optional";
+                    help = "Every column of a composite key must be set before the model is saved"
+                );
+            }
+        }
+
+        if let [id_field] = id_fields[..]
+            && let (Some(unsaved), Some(_)) =
+                (&fields[id_field].unsaved, fields[id_field].option_inner())
+        {
+            emit_error!(
+                unsaved,
+                "`unsaved` can not be used on an `Option` id";
+                help = "An id of `None` already marks the model as unsaved"
+            );
+        }
 
         Ok(Self {
             struct_sig,
             name,
             generics,
-            id_field,
+            table,
+            fields,
+            id_fields,
         })
     }
 
-    fn find_id_field(fields: &Fields) -> Option<Field> {
-        // First, look for a field with #[model(id)] attribute
-        for field in fields {
-            if Self::has_id_attribute(field) {
-                return Some(field.clone());
+    fn parse_table(attrs: &[Attribute]) -> Option<String> {
+        let mut table = None;
+
+        for attr in attrs {
+            if !attr.path().is_ident("model") {
+                continue;
             }
+
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("table") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    table = Some(name.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("Unknown model attribute"))
+                }
+            });
+
+            if let Err(err) = result {
+                emit_error!(
+                    err.span(), "Failed to parse model attribute: {}", err;
+                    help = "Supported struct attributes are: `table = \"...\"`"
+                );
+            }
+        }
+
+        table
+    }
+
+    fn find_id_fields(fields: &[ModelField]) -> Vec<usize> {
+        // First, look for fields with #[model(id)] attribute, several make up a composite key
+        let ids = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.id)
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        if !ids.is_empty() {
+            return ids;
         }
 
         // Fallback: look for a field named "id"
-        for field in fields {
-            if let Some(ident) = &field.ident {
-                if ident == "id" {
-                    return Some(field.clone());
+        fields
+            .iter()
+            .position(|field| field.ident == "id")
+            .into_iter()
+            .collect()
+    }
+
+    fn id_fields(&self) -> impl Iterator<Item = &ModelField> {
+        self.id_fields.iter().map(|&idx| &self.fields[idx])
+    }
+
+    fn expand(&self) -> TokenStream {
+        let name = &self.name;
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let crate_name = crate_name();
+
+        let (id_type, get_id) = match self.id_fields[..] {
+            [id_field] => Self::expand_single_id(&self.fields[id_field]),
+            _ => {
+                let id_types = self.id_fields().map(|field| &field.ty);
+                let id_idents = self.id_fields().map(|field| &field.ident);
+
+                (
+                    quote! { (#(#id_types),*) },
+                    quote! { Some((#(self.#id_idents.clone()),*)) },
+                )
+            }
+        };
+
+        // A key without unsaved semantics is set by the application and always present, so the
+        // id can not tell whether the model has been saved.
+        let is_new = (!self.id_fields().any(ModelField::is_unsaved_key)).then(|| {
+            quote! {
+                #[inline]
+                fn is_new(&self) -> bool {
+                    true
                 }
             }
+        });
+
+        let model_impl = quote! {
+            impl #impl_generics ::#crate_name::traits::Model for #name #ty_generics #where_clause {
+                type Id = #id_type;
+
+                fn get_id(&self) -> Option<Self::Id> {
+                    #get_id
+                }
+
+                #is_new
+            }
+        };
+
+        match &self.table {
+            Some(table) => {
+                let table_impl = self.expand_table(table);
+
+                quote! {
+                    #model_impl
+                    #table_impl
+                }
+            }
+            None => model_impl,
         }
+    }
+
+    /// Expands the id type and the body of `get_id` for a key made up of a single field.
+    fn expand_single_id(id_field: &ModelField) -> (TokenStream, TokenStream) {
+        let id_ident = &id_field.ident;
+        let id_type = &id_field.ty;
+
+        // An `Option` id is unsaved while `None`, a generated id or one with an explicit sentinel
+        // is unsaved while it holds the sentinel value, which defaults to `Default::default()`.
+        match id_field.option_inner() {
+            Some(inner) => (inner.to_token_stream(), quote! { self.#id_ident.clone() }),
+            None if id_field.generated || id_field.unsaved.is_some() => {
+                let unsaved = match &id_field.unsaved {
+                    Some(unsaved) => unsaved.to_token_stream(),
+                    None => quote! { <#id_type as ::core::default::Default>::default() },
+                };
 
-        None
+                (
+                    id_type.to_token_stream(),
+                    quote! {
+                        let unsaved: #id_type = #unsaved;
+
+                        if self.#id_ident == unsaved {
+                            None
+                        } else {
+                            Some(self.#id_ident.clone())
+                        }
+                    },
+                )
+            }
+            None => (
+                id_type.to_token_stream(),
+                quote! { Some(self.#id_ident.clone()) },
+            ),
+        }
     }
 
-    fn has_id_attribute(field: &Field) -> bool {
-        for attr in &field.attrs {
-            if attr.path().is_ident("model") {
-                let mut found = false;
-                let result = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("id") {
-                        found = true;
-                    }
-                    Ok(())
-                });
+    fn expand_table(&self, table: &str) -> TokenStream {
+        let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let crate_name = crate_name();
+
+        let id_columns = self
+            .id_fields()
+            .map(|field| &field.column)
+            .collect::<Vec<_>>();
+        let columns = self.fields.iter().map(|field| &field.column);
+
+        let insert_fields = self
+            .fields
+            .iter()
+            .filter(|field| field.is_insertable())
+            .collect::<Vec<_>>();
+        let update_fields = self
+            .fields
+            .iter()
+            .filter(|field| field.is_updatable())
+            .collect::<Vec<_>>();
 
-                if result.is_err() {
-                    emit_error!(attr.span(), "Failed to parse model attribute");
+        // Every key column is compared against its own placeholder, numbered from `first`.
+        let id_condition = |first: usize| {
+            id_columns
+                .iter()
+                .enumerate()
+                .map(|(idx, column)| format!("{column} = {}", placeholder(first + idx)))
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        };
+
+        let insert_query = if insert_fields.is_empty() {
+            if cfg!(all(
+                feature = "mysql",
+                not(any(
+                    feature = "sqlite",
+                    feature = "any",
+                    feature = "postgres"
+                ))
+            )) {
+                format!("INSERT INTO {table} () VALUES ()")
+            } else {
+                format!("INSERT INTO {table} DEFAULT VALUES")
+            }
+        } else {
+            let columns = insert_fields
+                .iter()
+                .map(|field| field.column.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let values = (1..=insert_fields.len())
+                .map(placeholder)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("INSERT INTO {table} ({columns}) VALUES ({values})")
+        };
+
+        // Without any updatable columns the key is assigned to itself, keeping the statement valid
+        // while leaving the row untouched.
+        let set_clause = if update_fields.is_empty() {
+            format!("{0} = {0}", id_columns[0])
+        } else {
+            update_fields
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| format!("{} = {}", field.column, placeholder(idx + 1)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let update_query = format!(
+            "UPDATE {table} SET {set_clause} WHERE {}",
+            id_condition(update_fields.len() + 1)
+        );
+
+        let delete_query = format!("DELETE FROM {table} WHERE {}", id_condition(1));
+        // The columns are listed explicitly so renamed columns are aliased to the field names
+        // `FromRow` decodes them from.
+        let select_columns = self
+            .fields
+            .iter()
+            .map(ModelField::select_expr)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let select_all_query = format!("SELECT {select_columns} FROM {table}");
+        let select_by_id_query = format!(
+            "SELECT {select_columns} FROM {table} WHERE {}",
+            id_condition(1)
+        );
+
+        let insert_columns = insert_fields.iter().map(|field| &field.column);
+        let update_columns = update_fields.iter().map(|field| &field.column);
+        let insert_binds = insert_fields
+            .iter()
+            .map(|field| &field.ident)
+            .collect::<Vec<_>>();
+        let update_binds = update_fields.iter().map(|field| &field.ident);
+        let id_idents = self.id_fields().map(|field| &field.ident);
+
+        // A composite key is a tuple, binding every element in the order of the key columns.
+        let (delete_binds, bind_id) = if self.id_fields.len() == 1 {
+            (quote! { .bind(id) }, quote! { query.bind(id) })
+        } else {
+            let indices = (0..self.id_fields.len())
+                .map(Index::from)
+                .collect::<Vec<_>>();
+
+            (
+                quote! { #(.bind(&id.#indices))* },
+                quote! { query #(.bind(id.#indices))* },
+            )
+        };
+
+        quote! {
+            impl #impl_generics ::#crate_name::traits::Table for #name #ty_generics #where_clause {
+                const TABLE_NAME: &'static str = #table;
+                const ID_COLUMNS: &'static [&'static str] = &[#(#id_columns),*];
+                const COLUMNS: &'static [&'static str] = &[#(#columns),*];
+                const INSERT_COLUMNS: &'static [&'static str] = &[#(#insert_columns),*];
+                const UPDATE_COLUMNS: &'static [&'static str] = &[#(#update_columns),*];
+                const SELECT_COLUMNS: &'static str = #select_columns;
+                const SELECT_ALL_QUERY: &'static str = #select_all_query;
+                const SELECT_BY_ID_QUERY: &'static str = #select_by_id_query;
+
+                #[inline]
+                fn insert_query(&self) -> ::#crate_name::types::Query<'_> {
+                    ::#crate_name::sqlx::query(#insert_query)
+                        #(.bind(&self.#insert_binds))*
+                }
+
+                #[inline]
+                fn push_insert_values<'args>(
+                    &'args self,
+                    row: &mut ::#crate_name::types::Separated<'_, 'args>,
+                ) {
+                    #(row.push_bind(&self.#insert_binds);)*
+                }
+
+                #[inline]
+                fn update_query(&self) -> ::#crate_name::types::Query<'_> {
+                    ::#crate_name::sqlx::query(#update_query)
+                        #(.bind(&self.#update_binds))*
+                        #(.bind(&self.#id_idents))*
                 }
 
-                if found {
-                    return true;
+                #[inline]
+                fn delete_by_id_query(id: &Self::Id) -> ::#crate_name::types::Query<'_> {
+                    ::#crate_name::sqlx::query(#delete_query)#delete_binds
+                }
+
+                #[inline]
+                fn bind_id<'q, O>(
+                    query: ::#crate_name::types::QueryAs<'q, O>,
+                    id: Self::Id,
+                ) -> ::#crate_name::types::QueryAs<'q, O>
+                where
+                    Self::Id: 'q,
+                {
+                    #bind_id
                 }
             }
         }
-        false
     }
-
-    fn expand(&self) -> TokenStream {
-      let name = &self.name;
-      let id_type = &self.id_field.ty;
-      let id_ident = &self.id_field.ident;
-      
-      let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
-      let crate_name = crate::types::crate_name();
-
-      quote! {
-          impl #impl_generics ::#crate_name::traits::Model for #name #ty_generics #where_clause {
-              type Id = #id_type;
-
-              fn get_id(&self) -> Option<Self::Id> {
-                  Some(self.#id_ident.clone())
-              }
-          }
-      }
-  }
 }
 
 impl ToTokens for DeriveModel {
@@ -21,8 +21,15 @@ mod types;
 /// sql_filter! {
 ///     [attributes]
 ///     visibility struct StructName {
-///         SELECT columns FROM table_name WHERE
+///         SELECT columns FROM table_name [alias]
+///         [[INNER|LEFT] JOIN table_name [alias] ON condition ...]
+///         WHERE
 ///         condition [AND|OR] condition ...
+///         [GROUP BY column, ...]
+///         [HAVING condition [AND|OR] condition ...]
+///         [ORDER BY ?column, ...]
+///         [[?]LIMIT type]
+///         [[?]OFFSET type]
 ///     }
 /// }
 /// ```
@@ -32,6 +39,26 @@ mod types;
 /// You can select all columns with `*` or specify individual columns:
 /// - `SELECT * FROM ...`: Select all columns
 /// - `SELECT col1, col2 as alias FROM ...`: Select specific columns with optional aliases
+/// - `SELECT o.*, c.name as customer_name FROM ...`: Columns qualified with their table
+/// - `SELECT 1 FROM ...`: An integer, like for a filter used with `exists`
+///
+/// ## Joins
+///
+/// The table can be given an alias and joined with other tables using `JOIN` (or `INNER JOIN`)
+/// and `LEFT [OUTER] JOIN`. The `ON` condition is written into the query as is, with string
+/// literals as raw SQL:
+/// ```ignore
+/// SELECT o.* FROM orders o
+/// JOIN customers c ON c.id = o.customer_id
+/// LEFT JOIN shipments s ON s.order_id = o.id AND s.state != "'cancelled'"
+/// WHERE ...
+/// ```
+///
+/// Conditions and `ORDER BY` columns can then be qualified with the table or its alias, like
+/// `?c.country = String`. The field of a qualified column has the table as a prefix, so `o.id`
+/// and `c.id` generate the fields `o_id` and `c_id`, and `ORDER BY ?c.name` the sort variant
+/// `CName`. Two conditions generating the same field name are rejected, give one of them an
+/// alias with `as` instead.
 ///
 /// ## Conditions
 ///
@@ -43,10 +70,84 @@ mod types;
 /// - Optional `?` prefix marks the field as optional in the filter
 /// - `column_name` is the database column name
 /// - Optional `as field_name` to use a different field name in the generated struct
-/// - `OPERATOR` is one of: `=`, `!=`, `>`, `<`, `>=`, `<=`, `LIKE`, `ILIKE`, `IN`, `NOT IN`
+/// - `OPERATOR` is one of: `=`, `!=`, `>`, `<`, `>=`, `<=`, `LIKE`, `ILIKE`, `IN`, `NOT IN`,
+///   `IS NULL`, `IS NOT NULL`, `BETWEEN`
 /// - `type` is either a Rust type (e.g., `i32`, `String`) or a raw SQL string literal
 ///
-/// Conditions can be combined with logical operators `AND`, `OR`, and `NOT`.
+/// `IS NULL`, `IS NOT NULL` and `BETWEEN` have their own forms:
+/// ```ignore
+/// [?]column_name [as field_name] IS [NOT] NULL
+/// [?]column_name [as field_name] BETWEEN type AND type
+/// ```
+///
+/// A required `IS [NOT] NULL` always applies and gets no field, an optional one gets a `bool`
+/// builder where `false` checks the opposite, so `?archived IS NOT NULL` with `.archived(false)`
+/// matches `archived IS NULL`. `BETWEEN` takes both bounds as a tuple, like `.created((from, to))`.
+///
+/// `IN` and `NOT IN` take a list, written either as `Vec<T>` or as the element type `T`. The
+/// field is a `Vec<T>` set from any iterator of values converting into `T`, like
+/// `.status([Status::Open, Status::Paid])`, and every element is bound as its own value. An empty
+/// list still applies, as a condition matching no rows for `IN` and every row for `NOT IN`.
+///
+/// Conditions can be combined with logical operators `AND`, `OR`, and `NOT`, and grouped with
+/// parentheses. `NOT` binds tightest, then `AND`, then `OR`, like in SQL. Every combination is
+/// wrapped in parentheses in the generated SQL, so it is always evaluated as written.
+///
+/// ## Ordering
+///
+/// The optional `ORDER BY` clause declares the columns the filter can be ordered on:
+/// ```ignore
+/// ORDER BY ?column [as VariantName], ...
+/// ```
+///
+/// A `{Name}Sort` enum is generated with a variant for every column, dropping `Filter` from the
+/// end of the struct name, so `UserFilter` generates `UserSort`. Variants default to the column
+/// name in `PascalCase`. The columns are only ordered on when picked with the `order_by` builder,
+/// and since only the declared columns can be picked, the sort order can safely come from user
+/// input.
+///
+/// ## Grouping and Limits
+///
+/// `GROUP BY` takes the columns to group on, and `HAVING` conditions like `WHERE` does, which can
+/// also be on aggregates like `?COUNT(o.id) as min_orders >= i64`. The field of an aggregate
+/// without an alias is named after the function and its arguments, so `SUM(o.total)` generates
+/// `sum_o_total`.
+///
+/// `LIMIT` and `OFFSET` generate the fields `limit` and `offset`, optional with a `?` prefix. The
+/// type must convert into a `u64`, and the value is always bound, never written into the query.
+/// Counting a filter with any of these clauses counts the rows it returns, not the rows matching
+/// the `WHERE` clause.
+///
+/// The clauses must come in the order `GROUP BY`, `HAVING`, `ORDER BY`, `LIMIT`, `OFFSET`. They
+/// belong to the filter itself and are dropped when it is combined with others through `and`,
+/// `or` or `not`.
+///
+/// ## Deserializing
+///
+/// A filter with `#[derive(Deserialize)]` on its struct implements `serde::Deserialize`, so it
+/// can be read straight from a query string or JSON body. The derive may name the `serde`
+/// re-exported by `sqlx_utils` with the `serde` feature or the user's own. Each field is read from the column name with a
+/// suffix for its operator, or from its alias if it has one. Qualified columns keep the prefix of
+/// their table, so `?c.country = String` is read from `c_country`:
+///
+/// | Operator      | Key                  |
+/// |---------------|----------------------|
+/// | `=`           | `column`             |
+/// | `!=`          | `column_ne`          |
+/// | `>`, `<`      | `column_gt`, `column_lt` |
+/// | `>=`, `<=`    | `column_gte`, `column_lte` |
+/// | `LIKE`        | `column_like`        |
+/// | `ILIKE`       | `column_ilike`       |
+/// | `IN`          | `column_in`          |
+/// | `NOT IN`      | `column_not_in`      |
+/// | `IS NULL`     | `column_is_null`     |
+/// | `IS NOT NULL` | `column_is_not_null` |
+/// | `BETWEEN`     | `column_between`     |
+///
+/// Optional fields are left unset when their key is missing while required fields must be
+/// present, and unknown keys are ignored. `?age >= i32` is read from `?age_gte=18`, and
+/// `?age as max_age <= i32` from `?max_age=65`. `LIMIT` and `OFFSET` are read from `limit` and
+/// `offset`, and the ordering is never deserialized.
 ///
 /// # Generated Code
 ///
@@ -55,6 +156,11 @@ mod types;
 /// 2. A constructor method for required fields
 /// 3. Builder methods for optional fields
 /// 4. Implementation of the `SqlFilter` trait
+/// 5. A sort enum and an `order_by` builder method for filters with an `ORDER BY` clause
+/// 6. Builder methods for `limit` and `offset` if the filter has these clauses
+/// 7. The `serde` attributes naming the query keys if the struct derives `Deserialize`
+/// 8. An implementation of the `SelectFilter` trait, so the filter can be used as a subquery with
+///    `in_subquery` and `exists`
 ///
 /// # Examples
 ///
@@ -115,18 +221,118 @@ mod types;
 ///     }
 /// }
 /// ```
+///
+/// ## Filter with Joins
+///
+/// ```rust,ignore
+/// # use sqlx_utils_macro::sql_filter;
+/// sql_filter! {
+///     pub struct OrderFilter {
+///         SELECT o.* FROM orders o
+///         JOIN customers c ON c.id = o.customer_id
+///         WHERE
+///         ?c.country = String AND
+///         ?o.total >= f64
+///     }
+/// }
+///
+/// // Usage:
+/// let filter = OrderFilter::new()
+///     .c_country("SE")
+///     .o_total(100.0);
+/// ```
+///
+/// ## Sortable Filter
+///
+/// ```rust,ignore
+/// # use sqlx_utils_macro::sql_filter;
+/// sql_filter! {
+///     pub struct UserFilter {
+///         SELECT * FROM users WHERE
+///         ?name LIKE String
+///         ORDER BY ?name, ?created_at
+///     }
+/// }
+///
+/// // Usage:
+/// let filter = UserFilter::new()
+///     .name("John%")
+///     .order_by(UserSort::CreatedAt, Direction::Desc);
+/// ```
+///
+/// ## Grouped Filter
+///
+/// ```rust,ignore
+/// # use sqlx_utils_macro::sql_filter;
+/// sql_filter! {
+///     pub struct CustomerFilter {
+///         SELECT c.* FROM customers c
+///         LEFT JOIN orders o ON o.customer_id = c.id
+///         WHERE
+///         ?c.country = String
+///         GROUP BY c.id
+///         HAVING ?COUNT(o.id) as min_orders >= i64
+///         ?LIMIT u32
+///     }
+/// }
+///
+/// // Usage:
+/// let filter = CustomerFilter::new()
+///     .min_orders(5)
+///     .limit(20u32);
+/// ```
 #[proc_macro_error2::proc_macro_error]
 #[proc_macro]
 pub fn sql_filter(token_stream: TokenStream) -> TokenStream {
     expand(token_stream)
 }
 
+/// Derives the `Model` trait, and optionally the `Table` trait, for a struct.
+///
+/// The primary key is the field marked with `#[model(id)]`, falling back to the field named `id`.
+/// Marking several fields creates a composite key, using a tuple of the field types as the id.
+///
+/// When the struct is annotated with `#[model(table = "...")]` the `Table` trait is also
+/// implemented, generating the `INSERT`, `UPDATE`, `DELETE` and `SELECT` queries for the table.
+///
+/// # Attributes
+///
+/// On the struct:
+/// - `#[model(table = "name")]`: The table backing the model, enables the `Table` implementation
+///
+/// On fields:
+/// - `#[model(id)]`: Marks the primary key, or a part of it for composite keys
+/// - `#[model(column = "name")]`: Overrides the column name, defaults to the field name
+/// - `#[model(skip_insert)]`: Leaves the field out of the `INSERT` statement
+/// - `#[model(skip_update)]`: Leaves the field out of the `UPDATE` statement
+/// - `#[model(generated)]`: The primary key is assigned by the database, leaving it out of the
+///   `INSERT` statement
+/// - `#[model(unsaved = expr)]`: The primary key value of a model that has not been saved yet,
+///   `generated` keys default to `Default::default()`
+///
+/// An `Option<T>` primary key uses `T` as the id type and `get_id` returns the field as is, so
+/// `None` marks the model as unsaved. Other keys are always considered saved unless `generated`
+/// or `unsaved` is given.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Model, FromRow)]
+/// #[model(table = "users")]
+/// struct User {
+///     #[model(id, generated)]
+///     id: i64,
+///     #[model(column = "user_name")]
+///     name: String,
+///     #[model(skip_update)]
+///     created_at: String,
+/// }
+/// ```
 #[proc_macro_error2::proc_macro_error]
 #[proc_macro_derive(Model, attributes(model))]
 pub fn derive_model(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
-    println!("Parsed input: {input:#?}");
-    
+
     match DeriveModel::new(input) {
         Ok(model) => model.to_token_stream().into(),
         Err(err) => err.into(),
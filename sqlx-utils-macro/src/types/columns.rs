@@ -1,11 +1,12 @@
+use crate::types::sql_text;
 #[cfg(feature = "try-parse")]
 use proc_macro_error2::emit_error;
-use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::{ToTokens, quote};
+use proc_macro2::{Ident, TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, format_ident, quote};
 use syn::parse::{Parse, ParseStream};
 #[cfg(feature = "try-parse")]
 use syn::parse_quote_spanned;
-use syn::{LitStr, Token, Type};
+use syn::{GenericArgument, LitStr, PathArguments, Token, Type};
 
 /// Represents column selection in an SQL query.
 ///
@@ -21,7 +22,9 @@ use syn::{LitStr, Token, Type};
 ///
 /// Parses input in either format:
 /// - `*`: All columns
-/// - `col1, col2 as alias2, ...`: Specific columns with optional aliases
+/// - `col1, t.col2 as alias2, t.*, ...`: Specific columns with optional aliases, which may be
+///   qualified with their table
+/// - `1`: An integer, like for the subquery of `EXISTS`
 ///
 /// # Code Generation
 ///
@@ -59,15 +62,27 @@ impl Parse for Columns {
         } else {
             let mut columns = Vec::new();
             while !input.is_empty() {
-                let name: Ident = input.parse()?;
+                let name =
+                    if input.peek(syn::Ident) && input.peek2(Token![.]) && input.peek3(Token![*]) {
+                        let table: Ident = input.parse()?;
+                        input.parse::<Token![.]>()?;
+                        input.parse::<Token![*]>()?;
+
+                        format!("{table}.*")
+                    } else if input.peek(syn::LitInt) {
+                        input.parse::<syn::LitInt>()?.to_string()
+                    } else {
+                        input.parse::<QualifiedColumn>()?.sql()
+                    };
+
                 let alias = if input.peek(Token![as]) {
                     input.parse::<Token![as]>()?;
                     let alias: Ident = input.parse()?;
                     alias.to_string()
                 } else {
-                    name.to_string()
+                    name.clone()
                 };
-                columns.push((name.to_string(), alias));
+                columns.push((name, alias));
 
                 if !input.peek(Token![,]) {
                     break;
@@ -79,6 +94,90 @@ impl Parse for Columns {
     }
 }
 
+/// A column, optionally qualified with the name or alias of its table like `c.country`, or a
+/// function call like `COUNT(*)` for the `HAVING` clause.
+///
+/// # Parsing
+///
+/// Parses input in the format:
+/// ```ignore
+/// [table.]column
+/// function(arguments)
+/// ```
+///
+/// # Field Names
+///
+/// The field generated for an unqualified column has the name of the column, while a qualified
+/// column gets the table as a prefix, so `o.id` and `c.id` become the fields `o_id` and `c_id`
+/// instead of colliding. A function call gets the lowercase name of the function followed by the
+/// names in its arguments, so `COUNT(*)` becomes `count` and `SUM(o.total)` becomes `sum_o_total`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct QualifiedColumn {
+    /// The name of the column or function, for error spans.
+    pub(crate) column: Ident,
+    pub(crate) field: Ident,
+    sql: String,
+}
+
+impl QualifiedColumn {
+    /// Creates an unqualified column.
+    pub(crate) fn new(column: Ident) -> Self {
+        Self {
+            field: column.clone(),
+            sql: column.to_string(),
+            column,
+        }
+    }
+
+    /// Gets the column as written in SQL, like `c.country`.
+    pub(crate) fn sql(&self) -> String {
+        self.sql.clone()
+    }
+
+    /// Collects the identifiers in `tokens`, for the field name of a function call.
+    fn idents(tokens: TokenStream2, idents: &mut Vec<String>) {
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => idents.push(ident.to_string().to_lowercase()),
+                TokenTree::Group(group) => Self::idents(group.stream(), idents),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Parse for QualifiedColumn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let first: Ident = input.parse()?;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let arguments: TokenStream2 = content.parse()?;
+
+            let mut idents = vec![first.to_string().to_lowercase()];
+            Self::idents(arguments.clone(), &mut idents);
+
+            Ok(Self {
+                field: format_ident!("{}", idents.join("_"), span = first.span()),
+                sql: format!("{}({})", first, sql_text(arguments)),
+                column: first,
+            })
+        } else if input.peek(Token![.]) && input.peek2(syn::Ident) {
+            input.parse::<Token![.]>()?;
+            let column: Ident = input.parse()?;
+
+            Ok(Self {
+                field: format_ident!("{}_{}", first, column, span = column.span()),
+                sql: format!("{}.{}", first, column),
+                column,
+            })
+        } else {
+            Ok(Self::new(first))
+        }
+    }
+}
+
 /// Represents a value type in a condition.
 ///
 /// This enum handles both Rust types and raw SQL expressions.
@@ -87,6 +186,9 @@ impl Parse for Columns {
 ///
 /// - `Type`: A Rust type like `i32` or `String`
 /// - `Raw`: A raw SQL string literal for direct inclusion in the query
+/// - `List`: A list of values for `IN` and `NOT IN`, holding the type of the elements
+/// - `Empty`: No value, used by operators like `IS NULL` on required conditions which always
+///   apply and so need no field
 ///
 /// # Parsing
 ///
@@ -101,6 +203,57 @@ impl Parse for Columns {
 pub(crate) enum ColumnVal {
     Type(Box<Type>),
     Raw(LitStr),
+    List(Box<Type>),
+    Empty,
+}
+
+impl ColumnVal {
+    /// Creates a list of `ty`, which is either the type of the elements or a `Vec` of them.
+    pub(crate) fn list(ty: Type) -> Self {
+        if let Type::Path(path) = &ty
+            && let Some(segment) = path.path.segments.last()
+            && let PathArguments::AngleBracketed(args) = &segment.arguments
+            && let (true, 1, Some(GenericArgument::Type(element))) =
+                (segment.ident == "Vec", args.args.len(), args.args.first())
+        {
+            return Self::List(Box::new(element.clone()));
+        }
+
+        Self::List(Box::new(ty))
+    }
+
+    /// Whether the value is bound from a field of the filter, instead of being fixed.
+    pub(crate) fn has_field(&self) -> bool {
+        matches!(self, Self::Type(_) | Self::List(_))
+    }
+
+    /// Gets the type of the field holding the value.
+    pub(crate) fn field_type(&self) -> Option<TokenStream2> {
+        match self {
+            Self::Type(ty) => Some(quote! {#ty}),
+            Self::List(ty) => Some(quote! {::std::vec::Vec<#ty>}),
+            Self::Raw(_) | Self::Empty => None,
+        }
+    }
+
+    /// Gets the type taken by the constructor or builder method of the field.
+    pub(crate) fn input_type(&self) -> Option<TokenStream2> {
+        match self {
+            Self::Type(ty) => Some(quote! {impl Into<#ty>}),
+            Self::List(ty) => Some(quote! {impl IntoIterator<Item = impl Into<#ty>>}),
+            Self::Raw(_) | Self::Empty => None,
+        }
+    }
+
+    /// Converts the input `name` of the constructor or builder method into the field type.
+    pub(crate) fn convert_input(&self, name: &Ident) -> TokenStream2 {
+        match self {
+            Self::List(_) => {
+                quote! {#name.into_iter().map(::core::convert::Into::into).collect()}
+            }
+            _ => quote! {#name.into()},
+        }
+    }
 }
 
 impl Parse for ColumnVal {
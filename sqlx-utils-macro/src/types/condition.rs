@@ -1,10 +1,12 @@
-use crate::types::columns::ColumnVal;
+use crate::types::columns::{ColumnVal, QualifiedColumn};
 use crate::types::sql_operator::SqlOperator;
 #[cfg(feature = "try-parse")]
 use proc_macro_error2::emit_error;
 use proc_macro2::Ident;
-use syn::Token;
 use syn::parse::{Parse, ParseStream};
+#[cfg(feature = "try-parse")]
+use syn::parse_quote_spanned;
+use syn::{Token, Type, parse_quote};
 
 /// Represents a single condition in the WHERE clause.
 ///
@@ -13,7 +15,7 @@ use syn::parse::{Parse, ParseStream};
 ///
 /// # Fields
 ///
-/// - `column_name`: The name of the database column
+/// - `column`: The database column, optionally qualified with its table
 /// - `field_alias`: Optional alternative name for the field in the generated struct
 /// - `operator`: The SQL operator to use for comparison
 /// - `column_type`: The type of the value (Rust type or raw SQL)
@@ -23,16 +25,26 @@ use syn::parse::{Parse, ParseStream};
 ///
 /// Parses conditions in the format:
 /// ```ignore
-/// [?]column_name [as field_alias] operator value_type
+/// [?][table.]column_name [as field_alias] operator value_type
+/// [?][table.]column_name [as field_alias] IS [NOT] NULL
+/// [?][table.]column_name [as field_alias] BETWEEN value_type AND value_type
 /// ```
 ///
 /// Where:
 /// - Optional `?` prefix marks the field as optional
-/// - `column_name` is the database column name
+/// - `column_name` is the database column name, qualified with the table or its alias when the
+///   filter joins several tables
 /// - Optional `as field_alias` specifies an alternative field name
 /// - `operator` is an SQL operator like `=`, `>`, `LIKE`, etc.
 /// - `value_type` is either a Rust type or a raw SQL string
 ///
+/// `IS NULL` and `IS NOT NULL` take no value, a required one always applies while an optional
+/// one gets a `bool` field where `false` checks the opposite. `BETWEEN` takes a Rust type for each
+/// bound and its field holds both bounds as a tuple.
+///
+/// `IN` and `NOT IN` take either `Vec<T>` or the element type `T`, both giving a `Vec<T>` field
+/// which is set from any iterator of values converting into `T`.
+///
 /// # Code Generation
 ///
 /// Expands to:
@@ -42,7 +54,7 @@ use syn::parse::{Parse, ParseStream};
 /// - Part of the `apply_filter` implementation
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct Condition {
-    pub(crate) column_name: Ident,
+    pub(crate) column: QualifiedColumn,
     pub(crate) field_alias: Option<Ident>,
     pub(crate) operator: SqlOperator,
     pub(crate) column_type: ColumnVal,
@@ -54,7 +66,65 @@ impl Condition {
         if let Some(alias) = &self.field_alias {
             alias
         } else {
-            &self.column_name
+            &self.column.field
+        }
+    }
+
+    /// The key of the field when the filter is deserialized with the `serde` feature.
+    ///
+    /// This is the alias if there is one, otherwise the field name with the suffix of the
+    /// operator, like `age_gte` for `age >= i32`.
+    pub(crate) fn serde_key(&self) -> String {
+        match &self.field_alias {
+            Some(alias) => alias.to_string(),
+            None => format!("{}{}", self.column.field, self.operator.serde_suffix()),
+        }
+    }
+}
+
+impl Condition {
+    /// Parses the `low AND high` bounds of a `BETWEEN` condition into a tuple type.
+    fn parse_bounds(input: ParseStream) -> syn::Result<ColumnVal> {
+        let low = Self::parse_bound(input)?;
+
+        let and: Ident = input.parse()?;
+        if !and.to_string().eq_ignore_ascii_case("AND") {
+            return Err(syn::Error::new(
+                and.span(),
+                format!(
+                    "Expected `AND` between the bounds of `BETWEEN`, found `{}`",
+                    and
+                ),
+            ));
+        }
+
+        let high = Self::parse_bound(input)?;
+
+        Ok(ColumnVal::Type(Box::new(parse_quote! { (#low, #high) })))
+    }
+
+    fn parse_bound(input: ParseStream) -> syn::Result<Type> {
+        match input.parse()? {
+            ColumnVal::Type(ty) => Ok(*ty),
+            ColumnVal::Raw(lit) => {
+                #[cfg(not(feature = "try-parse"))]
+                proc_macro_error2::abort!(
+                    lit, "The bounds of `BETWEEN` must be Rust types";
+                    help = "Raw SQL is not supported as a bound, use a type like `i64` instead"
+                );
+
+                #[cfg(feature = "try-parse")]
+                emit_error!(
+                    lit, "The bounds of `BETWEEN` must be Rust types";
+                    help = "Raw SQL is not supported as a bound, use a type like `i64` instead"
+                );
+
+                #[cfg(feature = "try-parse")]
+                Ok(parse_quote_spanned! { lit.span()=> () })
+            }
+            ColumnVal::List(_) | ColumnVal::Empty => {
+                unreachable!("a parsed value is a type or raw SQL")
+            }
         }
     }
 }
@@ -66,7 +136,7 @@ impl Parse for Condition {
             input.parse::<Token![?]>()?;
         }
 
-        let column_name = input.parse().unwrap_or_else(|err| {
+        let column = input.parse().unwrap_or_else(|err| {
             let span = err.span();
             #[cfg(not(feature = "try-parse"))]
             proc_macro_error2::abort!(
@@ -81,7 +151,7 @@ impl Parse for Condition {
             );
 
             #[cfg(feature = "try-parse")]
-            Ident::new("__err__", span)
+            QualifiedColumn::new(Ident::new("__err__", span))
         });
 
         let mut field_alias = None;
@@ -110,12 +180,27 @@ impl Parse for Condition {
             });
         }
 
-        let operator = input.parse()?; // FIXME: Add error logging to SQL operators
+        let operator: SqlOperator = input.parse()?; // FIXME: Add error logging to SQL operators
 
-        let column_type = input.parse()?;
+        let column_type = if operator.is_unary() {
+            if optional {
+                ColumnVal::Type(Box::new(parse_quote! { bool }))
+            } else {
+                ColumnVal::Empty
+            }
+        } else if let SqlOperator::Between = operator {
+            Self::parse_bounds(input)?
+        } else if let SqlOperator::In | SqlOperator::NotIn = operator {
+            match input.parse()? {
+                ColumnVal::Type(ty) => ColumnVal::list(*ty),
+                column_type => column_type,
+            }
+        } else {
+            input.parse()?
+        };
 
         Ok(Self {
-            column_name,
+            column,
             field_alias,
             operator,
             column_type,
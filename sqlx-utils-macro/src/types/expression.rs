@@ -1,6 +1,6 @@
 use crate::types::columns::ColumnVal;
 use crate::types::condition::Condition;
-use crate::types::crate_name;
+use crate::types::{crate_name, peek_keyword};
 #[cfg(feature = "try-parse")]
 use proc_macro_error2::emit_error;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
@@ -8,7 +8,10 @@ use quote::{ToTokens, format_ident, quote};
 use std::collections::HashMap;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{TypePath, parse_quote};
+use syn::{Token, TypePath, parse_quote};
+
+/// The clauses that can follow the `WHERE` and `HAVING` expressions.
+const CLAUSES: &[&str] = &["GROUP", "HAVING", "ORDER", "LIMIT", "OFFSET"];
 
 /// Represents a logical expression in the WHERE clause.
 ///
@@ -29,9 +32,13 @@ use syn::{TypePath, parse_quote};
 /// condition AND condition
 /// condition OR condition
 /// NOT condition
+/// (expression)
 /// condition
 /// ```
 ///
+/// `NOT` binds tightest, then `AND`, then `OR`, like in SQL, so `a OR b AND c` is parsed as
+/// `a OR (b AND c)`.
+///
 /// # Code Generation
 ///
 /// Expands to methods on the filter struct that implement the corresponding
@@ -39,6 +46,9 @@ use syn::{TypePath, parse_quote};
 /// - `AND`: `.and()`
 /// - `OR`: `.or()`
 /// - `NOT`: `.not()`
+///
+/// The runtime operators wrap themselves in parentheses, so the generated SQL is grouped exactly
+/// like the parsed tree and a `Group` needs no code of its own.
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) enum Expression {
     And(Box<Expression>, Box<Expression>),
@@ -55,6 +65,21 @@ pub(crate) enum Expression {
 
 impl Expression {
     fn parse_inner(input: ParseStream, start_span: Span) -> syn::Result<Self> {
+        let left = Self::parse_primary(input, start_span)?;
+
+        left.parse_operator(input)
+    }
+
+    /// Parses a single operand of `AND` and `OR`: a condition, a parenthesized expression or
+    /// either of them prefixed with `NOT`.
+    fn parse_primary(input: ParseStream, start_span: Span) -> syn::Result<Self> {
+        if peek_keyword(input, "NOT") {
+            input.parse::<Ident>()?;
+
+            let expr = Self::parse_primary(input, input.span())?;
+            return Ok(Expression::Not(Box::new(expr)));
+        }
+
         if input.peek(syn::token::Paren) {
             let content;
             syn::parenthesized!(content in input);
@@ -86,22 +111,33 @@ impl Expression {
                 );
             }
 
-            if !input.is_empty() {
-                return expr.parse_operator(input);
-            }
-
             return Ok(Expression::Group(Box::new(expr)));
         }
 
         // Base condition
         let condition = input.parse()?;
         let span = start_span.join(input.span()).unwrap_or(start_span);
-        let left = Expression::Condition { condition, span };
 
-        left.parse_operator(input)
+        Ok(Expression::Condition { condition, span })
+    }
+
+    /// Whether the input continues with a clause that ends the `WHERE` or `HAVING` expression,
+    /// including the optional `?LIMIT` and `?OFFSET` clauses.
+    fn at_clause_end(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        if fork.peek(Token![?]) {
+            let _ = fork.parse::<Token![?]>();
+        }
+
+        CLAUSES.iter().any(|clause| peek_keyword(&fork, clause))
     }
 
     pub fn parse_operator(self, input: ParseStream) -> syn::Result<Self> {
+        if Self::at_clause_end(input) {
+            return Ok(self);
+        }
+
         let op: Option<Ident> = input.parse().unwrap_or_else(|err| {
             #[cfg(not(feature = "try-parse"))]
             proc_macro_error2::abort!(err.span(), "Failed to parse operator");
@@ -113,18 +149,33 @@ impl Expression {
             None
         });
 
+        let op_span = op.as_ref().map_or_else(Span::call_site, Ident::span);
+
         match op.map(|i| i.to_string().to_uppercase()) {
+            // `AND` binds tighter than `OR`, so only the next operand belongs to it
             Some(op) if op == *"AND" => {
-                let right = Self::parse(input).unwrap_or(Expression::Empty);
-                Ok(Expression::And(Box::new(self), Box::new(right)))
+                let right = Self::parse_primary(input, input.span()).unwrap_or(Expression::Empty);
+                Expression::And(Box::new(self), Box::new(right)).parse_operator(input)
             }
             Some(op) if op == *"OR" => {
                 let right = Self::parse(input).unwrap_or(Expression::Empty);
                 Ok(Expression::Or(Box::new(self), Box::new(right)))
             }
             Some(op) if op == *"NOT" => {
-                let expr = Self::parse(input).unwrap_or(Expression::Empty);
-                Ok(Expression::Not(Box::new(expr)))
+                #[cfg(not(feature = "try-parse"))]
+                proc_macro_error2::abort!(
+                    op_span, "`NOT` can not combine two conditions";
+                    help = "Use `AND NOT` or `OR NOT` to negate the next condition"
+                );
+
+                #[cfg(feature = "try-parse")]
+                emit_error!(
+                    op_span, "`NOT` can not combine two conditions";
+                    help = "Use `AND NOT` or `OR NOT` to negate the next condition"
+                );
+
+                #[cfg(feature = "try-parse")]
+                Ok(Expression::Empty)
             }
             None => Ok(self),
             Some(op) => {
@@ -150,8 +201,8 @@ impl Expression {
 
     pub(crate) fn fields_with_cache<'a>(
         &'a self,
-        cache: &mut HashMap<usize, Vec<(&'a Ident, &'a ColumnVal, bool)>>,
-    ) -> Vec<(&'a Ident, &'a ColumnVal, bool)> {
+        cache: &mut HashMap<usize, Vec<(&'a Ident, &'a ColumnVal, bool, String)>>,
+    ) -> Vec<(&'a Ident, &'a ColumnVal, bool, String)> {
         let self_ptr = self as *const Self as usize;
 
         if let Some(cached) = cache.get(&self_ptr) {
@@ -165,6 +216,7 @@ impl Expression {
                 condition.rust_name(),
                 &condition.column_type,
                 condition.optional,
+                condition.serde_key(),
             )),
             Expression::And(left, right) => {
                 fields.extend(left.fields_with_cache(cache));
@@ -198,7 +250,7 @@ impl ToTokens for Expression {
                 let crate_name = crate_name();
 
                 let operator = &c.operator;
-                let column = &c.column_name;
+                let column = c.column.sql();
 
                 if let ColumnVal::Raw(lit) = &c.column_type {
                     let path: TypePath = parse_quote! {#operator};
@@ -206,14 +258,27 @@ impl ToTokens for Expression {
                     let ident = &seg.ident;
                     let new_ident = format_ident!("{}_raw", ident);
 
-                    quote! { ::#crate_name::filter::#new_ident(stringify!(#column), ::#crate_name::filter::Raw(#lit)) }
+                    quote! { ::#crate_name::filter::#new_ident(#column, ::#crate_name::filter::Raw::unsafe_raw(#lit)) }
+                } else if let ColumnVal::List(_) = &c.column_type {
+                    let path: TypePath = parse_quote! {#operator};
+                    let seg = path.path.segments.last().unwrap();
+                    let new_ident = format_ident!("{}_list", seg.ident);
+                    let rust_name = c.rust_name();
+
+                    if c.optional {
+                        quote! { ::#crate_name::filter::#new_ident(#column, self.#rust_name) }
+                    } else {
+                        quote! { ::#crate_name::filter::#new_ident(#column, Some(self.#rust_name)) }
+                    }
+                } else if let ColumnVal::Empty = &c.column_type {
+                    quote! { #operator(#column, Some(true)) }
                 } else {
                     let rust_name = c.rust_name();
 
                     if c.optional {
-                        quote! { #operator(stringify!(#column), self.#rust_name) }
+                        quote! { #operator(#column, self.#rust_name) }
                     } else {
-                        quote! { #operator(stringify!(#column), Some(self.#rust_name)) }
+                        quote! { #operator(#column, Some(self.#rust_name)) }
                     }
                 }
             }
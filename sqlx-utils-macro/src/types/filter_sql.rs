@@ -1,5 +1,10 @@
 use crate::types::columns::Columns;
 use crate::types::expression::Expression;
+use crate::types::group_by::GroupBy;
+use crate::types::join::{Join, parse_table_alias};
+use crate::types::limit::RowLimit;
+use crate::types::order_by::OrderBy;
+use crate::types::peek_keyword;
 use proc_macro_error2::abort;
 #[cfg(feature = "try-parse")]
 use proc_macro_error2::emit_error;
@@ -16,25 +21,85 @@ use syn::parse::{Parse, ParseStream};
 ///
 /// Parses input in the format:
 /// ```ignore
-/// SELECT columns FROM table_name WHERE expression
+/// SELECT columns FROM table_name [[AS] alias] [joins] WHERE expression
+/// [GROUP BY column, ...] [HAVING expression] [ORDER BY ?column, ...]
+/// [[?]LIMIT type] [[?]OFFSET type]
 /// ```
 ///
 /// Where:
 /// - `columns` can be `*` or a comma-separated list of column names with optional aliases
 /// - `table_name` is the name of the database table
+/// - `alias` is the alias of the table, to qualify its columns with
+/// - `joins` are `[INNER | LEFT] JOIN` clauses, see [`Join`]
 /// - `expression` is a boolean expression combining filter conditions
+/// - `GROUP BY` and `HAVING` group the rows, with the `HAVING` expression using the same
+///   grammar as the `WHERE` expression
+/// - `column` is a column the filter can be ordered on
+/// - `LIMIT` and `OFFSET` get a field each, see [`RowLimit`]
 ///
 /// # Fields
 ///
 /// - `columns`: The columns to select (either all columns or specific ones)
 /// - `table_name`: The name of the database table
+/// - `table_alias`: The alias of the table, if any
+/// - `joins`: The joined tables
 /// - `expr`: The parsed filter expression
+/// - `group_by`: The grouped columns, if any
+/// - `having`: The parsed `HAVING` expression, if any
+/// - `order_by`: The sortable columns, if any
+/// - `limit`, `offset`: The `LIMIT` and `OFFSET` clauses, if any
 #[allow(dead_code)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct FilterSql {
     pub(crate) columns: Columns,
     pub(crate) table_name: Ident,
+    pub(crate) table_alias: Option<Ident>,
+    pub(crate) joins: Vec<Join>,
     pub(crate) expr: Expression,
+    pub(crate) group_by: Option<GroupBy>,
+    pub(crate) having: Option<Expression>,
+    pub(crate) order_by: Option<OrderBy>,
+    pub(crate) limit: Option<RowLimit>,
+    pub(crate) offset: Option<RowLimit>,
+}
+
+impl FilterSql {
+    /// Gets the query up to the `WHERE` clause, like `SELECT o.* FROM orders o JOIN ...`.
+    pub(crate) fn select_sql(&self) -> String {
+        let columns = match &self.columns {
+            Columns::All => String::from("*"),
+            Columns::Defined(cols) => cols
+                .iter()
+                .map(|(name, alias)| {
+                    if name != alias {
+                        format!("{name} as {alias}")
+                    } else {
+                        name.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+
+        format!("SELECT {columns} FROM {}", self.table_sql())
+    }
+
+    /// Gets the `FROM` clause without the keyword, like `orders o INNER JOIN customers c ON ...`.
+    pub(crate) fn table_sql(&self) -> String {
+        let mut from = self.table_name.to_string();
+
+        if let Some(alias) = &self.table_alias {
+            from.push(' ');
+            from.push_str(&alias.to_string());
+        }
+
+        for join in &self.joins {
+            from.push(' ');
+            from.push_str(&join.sql());
+        }
+
+        from
+    }
 }
 
 impl Parse for FilterSql {
@@ -112,7 +177,16 @@ impl Parse for FilterSql {
             }
         }
 
+        let mut table_alias = None;
+        let mut joins = Vec::new();
+
         if !table_name_err {
+            table_alias = parse_table_alias(input)?;
+
+            while Join::peek(input) {
+                joins.push(input.parse()?);
+            }
+
             let where_ident = input.parse::<Ident>().unwrap_or_else(|err| {
                 let span = err.span();
 
@@ -143,10 +217,45 @@ impl Parse for FilterSql {
 
         let expr = input.parse()?;
 
+        let group_by = if peek_keyword(input, "GROUP") {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let having = if peek_keyword(input, "HAVING") {
+            input.parse::<Ident>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let order_by = if peek_keyword(input, "ORDER") {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let limit = RowLimit::parse_clause(input, "LIMIT")?;
+        let offset = RowLimit::parse_clause(input, "OFFSET")?;
+
+        if !input.is_empty() {
+            return Err(input.error(
+                "Expected `GROUP BY`, `HAVING`, `ORDER BY`, `LIMIT` or `OFFSET` in this order after the `WHERE` clause",
+            ));
+        }
+
         Ok(FilterSql {
             columns,
             table_name,
+            table_alias,
+            joins,
             expr,
+            group_by,
+            having,
+            order_by,
+            limit,
+            offset,
         })
     }
 }
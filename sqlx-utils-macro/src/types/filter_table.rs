@@ -1,18 +1,16 @@
-use crate::types::columns::ColumnVal;
 use crate::types::filter_sql::FilterSql;
+use crate::types::order_by::OrderBy;
 use crate::types::{crate_name, database_type};
-use proc_macro_error2::abort_call_site;
+use proc_macro_error2::abort;
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{ToTokens, quote};
 use std::collections::HashMap;
-use std::fmt::Write;
 use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
 use syn::token::Brace;
-use syn::{Attribute, Token, Type, Visibility, parse_quote};
+use syn::{Attribute, Path, Token, Type, Visibility, parse_quote};
 use syn_derive::Parse;
 
-use super::columns::Columns;
-
 /// Top-level structure representing a SQL filter definition.
 ///
 /// This struct is the main entry point for parsing the `sql_filter!` macro input.
@@ -37,6 +35,8 @@ use super::columns::Columns;
 /// 2. A constructor method with required fields as parameters
 /// 3. Builder methods for optional fields (those prefixed with `?`)
 /// 4. Implementation of the `SqlFilter` trait
+/// 5. A sort enum and `order_by` builder method if the filter has an `ORDER BY` clause
+/// 6. Implementation of the `SelectFilter` trait with the query up to the `WHERE` clause
 ///
 /// The generated struct implements the `SqlFilter` trait with:
 /// - `apply_filter`: Applies the filter conditions to a query builder
@@ -87,44 +87,7 @@ impl RepositoryIdent {
         sql: &FilterSql,
         token_stream: &mut TokenStream2,
     ) {
-        let FilterSql {
-            columns,
-            table_name,
-            ..
-        } = sql;
-
-        let mut query_str = String::from("SELECT");
-
-        match columns {
-            Columns::All => write!(query_str, " *").unwrap_or_else(|_| {
-                abort_call_site!(
-                    "Failed to write column into string, please rapport this as an issue!"
-                )
-            }),
-            Columns::Defined(cols) => {
-                let columns = cols
-                    .iter()
-                    .cloned()
-                    .map(|(name, alias)| {
-                        if name != alias {
-                            format!("{name} as {alias}")
-                        } else {
-                            name
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                write!(query_str, " {columns}").unwrap_or_else(|_| {
-                    abort_call_site!(
-                        "Failed to write column into string, please rapport this as an issue!"
-                    )
-                })
-            }
-        }
-
-        write!(query_str, " FROM {} ", table_name).unwrap_or_else(|_| {
-            abort_call_site!("Failed to write column into string, please rapport this as an issue!")
-        });
+        let query_str = sql.select_sql();
 
         //M: Model + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Send + Unpin,
         let repo_ident = &self.repo_type;
@@ -158,6 +121,22 @@ impl RepositoryIdent {
     }
 }
 
+/// Checks if `meta` derives `Deserialize`, by any path like `serde::Deserialize`.
+fn derives_deserialize<'a>(meta: impl IntoIterator<Item = &'a Attribute>) -> bool {
+    meta.into_iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|seg| seg.ident == "Deserialize")
+        })
+}
+
 impl ToTokens for FilterTable {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let crate_name = crate_name();
@@ -171,76 +150,143 @@ impl ToTokens for FilterTable {
             ..
         } = self;
 
+        // Only filters deriving `Deserialize` themselves get the field names of the query keys
+        let deserialize = derives_deserialize(meta);
+
         let mut cache = HashMap::new();
-        let fields = sql.expr.fields_with_cache(&mut cache);
-        let token_fields = fields.iter().filter_map(|(name, ty, optional)| {
-            if let ColumnVal::Type(ty) = ty {
-                if *optional {
-                    Some(quote! {#name: Option<#ty>})
-                } else {
-                    Some(quote! {#name: #ty})
-                }
+        let where_fields = sql.expr.fields_with_cache(&mut cache);
+
+        // Only the conditions of the `WHERE` clause decide if the filter should be applied
+        let where_optional_fields = where_fields
+            .iter()
+            .filter(|(_, ty, optional, _)| *optional && ty.has_field())
+            .map(|(name, _, _, _)| *name)
+            .collect::<Vec<_>>();
+        let where_req_fields = where_fields
+            .iter()
+            .any(|(_, ty, optional, _)| !*optional && ty.has_field());
+
+        let mut fields = where_fields.clone();
+
+        if let Some(having) = &sql.having {
+            fields.extend(having.fields_with_cache(&mut cache));
+        }
+
+        for limit in [&sql.limit, &sql.offset].into_iter().flatten() {
+            fields.push((
+                &limit.field,
+                &limit.column_type,
+                limit.optional,
+                limit.field.to_string(),
+            ));
+        }
+
+        let token_fields = fields.iter().filter_map(|(name, ty, optional, key)| {
+            let serde_attr = deserialize.then(|| quote! {#[serde(rename = #key)]});
+            let ty = ty.field_type()?;
+
+            if *optional {
+                Some(quote! {#serde_attr #name: Option<#ty>})
             } else {
-                None
+                Some(quote! {#serde_attr #name: #ty})
             }
         });
 
+        // Raw values and `IS NULL` on a required column have no field and always apply
+        let has_fixed_conditions = where_fields.iter().any(|(_, ty, _, _)| !ty.has_field());
+
         let fields = fields
             .iter()
-            .filter(|(_, ty, _)| matches!(ty, ColumnVal::Type(_)))
+            .filter(|(_, ty, _, _)| ty.has_field())
             .collect::<Vec<_>>();
 
+        for (idx, (name, _, _, _)) in fields.iter().enumerate() {
+            if fields[..idx].iter().any(|(prev, _, _, _)| prev == name) {
+                abort!(
+                    name, "The field `{}` is generated by more than one condition", name;
+                    help = "Give the other conditions their own field name with `as`, like `?c.id as customer_id = i64`"
+                );
+            }
+        }
+
         let optional_fields = fields
             .iter()
-            .filter(|(_, _, optional)| *optional)
+            .filter(|(_, _, optional, _)| *optional)
             .collect::<Vec<_>>();
 
-        let optional_field_names = optional_fields.iter().map(|(name, _, _)| quote! {#name});
+        let optional_field_names = optional_fields.iter().map(|(name, _, _, _)| quote! {#name});
 
-        let optional_field_builder = optional_fields.iter().map(|(name, ty, _)| {
-            if let ColumnVal::Type(ty) = ty {
-                quote! {
-                    #[inline]
-                    #vis fn #name(mut self, #name: impl Into<#ty>) -> Self {
-                        self.#name = Some(#name.into());
-                        self
-                    }
+        let optional_field_builder = optional_fields.iter().map(|(name, ty, _, _)| {
+            let input = ty.input_type();
+            let convert = ty.convert_input(name);
+
+            quote! {
+                #[inline]
+                #vis fn #name(mut self, #name: #input) -> Self {
+                    self.#name = Some(#convert);
+                    self
                 }
-            } else {
-                quote! {compile_error!("Found Raw type among fields")}
             }
         });
 
         let req_fields = fields
             .iter()
-            .filter(|(_, _, optional)| !*optional)
+            .filter(|(_, _, optional, _)| !*optional)
             .collect::<Vec<_>>();
 
-        let req_fields_fn_input = req_fields.iter().map(|(name, ty, _)| {
-            if let ColumnVal::Type(ty) = ty {
-                quote! {#name: impl Into<#ty>}
-            } else {
-                quote! {compile_error!("Found Raw type among fields")}
-            }
+        let req_fields_fn_input = req_fields.iter().map(|(name, ty, _, _)| {
+            let input = ty.input_type();
+
+            quote! {#name: #input}
         });
 
-        let req_fields_into = req_fields.iter().map(|(name, _, _)| {
-            quote! {let #name = #name.into();}
+        let req_fields_into = req_fields.iter().map(|(name, ty, _, _)| {
+            let convert = ty.convert_input(name);
+
+            quote! {let #name = #convert;}
         });
 
-        let req_field_names = req_fields.iter().map(|(name, _, _)| quote! {#name});
+        let req_field_names = req_fields.iter().map(|(name, _, _, _)| quote! {#name});
+
+        let order_field = sql.order_by.as_ref().map(|_| {
+            let sort = OrderBy::sort_ident(name);
+
+            let serde_attr = deserialize.then(|| quote! {#[serde(skip)]});
+
+            quote! { #serde_attr __order: ::std::vec::Vec<(#sort, ::#crate_name::types::Direction)>, }
+        });
+
+        let order_init = sql
+            .order_by
+            .as_ref()
+            .map(|_| quote! { __order: ::std::vec::Vec::new(), });
+
+        let order_builder = sql.order_by.as_ref().map(|_| {
+            let sort = OrderBy::sort_ident(name);
+
+            quote! {
+                /// Orders the rows by `sort` in `direction`, after any ordering added before.
+                #[inline]
+                #vis fn order_by(mut self, sort: #sort, direction: ::#crate_name::types::Direction) -> Self {
+                    self.__order.push((sort, direction));
+                    self
+                }
+            }
+        });
 
         let struct_init = if !req_fields.is_empty() {
             quote! {
                 Self {
                     #(#req_field_names),*,
-                    #(#optional_field_names: None),*
+                    #(#optional_field_names: None,)*
+                    #order_init
                 }
             }
         } else {
             quote! {
                 Self {
-                    #(#optional_field_names: None),*
+                    #(#optional_field_names: None,)*
+                    #order_init
                 }
             }
         };
@@ -257,10 +303,21 @@ impl ToTokens for FilterTable {
         #[cfg(feature = "filter_debug_impl")]
         meta.push(&debug_meta);
 
+        // The derive may name the `serde` re-exported by this crate or the one of the user
+        let serde_meta = (deserialize && cfg!(feature = "serde")).then(|| {
+            let serde_crate = format!("::{crate_name}::serde");
+
+            quote! {
+                #[serde(crate = #serde_crate)]
+            }
+        });
+
         let struct_def = quote! {
             #(#meta)*
+            #serde_meta
             #vis struct #name {
                 #(#token_fields,)*
+                #order_field
             }
 
             impl #name {
@@ -272,23 +329,29 @@ impl ToTokens for FilterTable {
                 }
 
                 #(#optional_field_builder)*
+
+                #order_builder
             }
         };
 
         tokens.extend(struct_def);
 
+        if let Some(order_by) = &sql.order_by {
+            tokens.extend(order_by.expand_sort_enum(vis, name));
+        }
+
         let FilterSql { expr, .. } = sql;
 
         let db_type = database_type();
 
-        let should_apply_filter_impl = if !optional_fields.is_empty() {
+        let should_apply_filter_impl = if !where_optional_fields.is_empty() {
             let mut impl_tokens = vec![];
 
-            if !req_fields.is_empty() {
+            if where_req_fields || has_fixed_conditions {
                 impl_tokens.push(quote! {true});
             }
 
-            for (ident, _, _) in optional_fields {
+            for ident in where_optional_fields {
                 impl_tokens.push(quote! {self.#ident.is_some()})
             }
 
@@ -297,7 +360,84 @@ impl ToTokens for FilterTable {
             quote! {true}
         };
 
+        let ordering_impl = sql.order_by.as_ref().map(|_| {
+            quote! {
+                #[inline]
+                fn ordering(&self) -> ::std::vec::Vec<(&'static str, ::#crate_name::types::Direction)> {
+                    self.__order
+                        .iter()
+                        .map(|(sort, direction)| (sort.column(), *direction))
+                        .collect()
+                }
+            }
+        });
+
+        let clauses_impl = (sql.group_by.is_some() || sql.having.is_some()).then(|| {
+            let group_by = sql.group_by.as_ref().map(|group_by| {
+                let group_by = format!(" {}", group_by.sql());
+
+                quote! { builder.push(#group_by); }
+            });
+
+            let having = sql.having.as_ref().map(|having| {
+                quote! {
+                    let having = #having;
+
+                    if ::#crate_name::traits::SqlFilter::should_apply_filter(&having) {
+                        builder.push(" HAVING ");
+                        ::#crate_name::traits::SqlFilter::apply_filter(having, builder);
+                    }
+                }
+            });
+
+            quote! {
+                #[inline]
+                fn apply_clauses(self, builder: &mut ::sqlx_utils::types::QueryBuilder<'args, #db_type>) {
+                    if ::#crate_name::traits::SqlFilter::should_apply_filter(&self) {
+                        builder.push(" WHERE ");
+                        ::#crate_name::traits::SqlFilter::apply_filter(#expr, builder);
+                    }
+
+                    #group_by
+                    #having
+                }
+
+                #[inline]
+                fn groups_rows(&self) -> bool {
+                    true
+                }
+            }
+        });
+
+        let limit_impl = sql.limit.as_ref().map(|limit| {
+            let value = limit.expand_value();
+
+            quote! {
+                #[inline]
+                fn row_limit(&self) -> ::core::option::Option<u64> {
+                    #value
+                }
+            }
+        });
+
+        let offset_impl = sql.offset.as_ref().map(|offset| {
+            let value = offset.expand_value();
+
+            quote! {
+                #[inline]
+                fn row_offset(&self) -> ::core::option::Option<u64> {
+                    #value
+                }
+            }
+        });
+
+        let select_sql = sql.select_sql();
+
         let expanded = quote! {
+            impl ::#crate_name::traits::SelectFilter for #name {
+                const QUERY: &'static str = #select_sql;
+            }
+
             impl<'args> #crate_name::traits::SqlFilter<'args> for #name {
                 #[inline]
                 fn apply_filter(self, builder: &mut ::sqlx_utils::types::QueryBuilder<'args, #db_type>) {
@@ -308,6 +448,14 @@ impl ToTokens for FilterTable {
                 fn should_apply_filter(&self) -> bool {
                     #should_apply_filter_impl
                 }
+
+                #ordering_impl
+
+                #clauses_impl
+
+                #limit_impl
+
+                #offset_impl
             }
         };
 
@@ -0,0 +1,63 @@
+use crate::types::columns::QualifiedColumn;
+use proc_macro_error2::abort;
+use proc_macro2::Ident;
+use syn::Token;
+use syn::parse::{Parse, ParseStream};
+
+/// Represents the `GROUP BY` clause of a filter definition.
+///
+/// # Parsing
+///
+/// Parses input in the format:
+/// ```ignore
+/// GROUP BY [table.]column, ...
+/// ```
+///
+/// # Code Generation
+///
+/// The clause is written into the query as is after the `WHERE` clause, followed by the
+/// `HAVING` clause if the filter has one.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct GroupBy {
+    pub(crate) columns: Vec<QualifiedColumn>,
+}
+
+impl GroupBy {
+    /// Gets the clause as written in SQL.
+    pub(crate) fn sql(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(QualifiedColumn::sql)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("GROUP BY {columns}")
+    }
+}
+
+impl Parse for GroupBy {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        for keyword in ["GROUP", "BY"] {
+            let ident = input.parse::<Ident>()?;
+
+            if !ident.to_string().eq_ignore_ascii_case(keyword) {
+                abort!(
+                    ident,
+                    "Expected `{}` but instead found `{}`",
+                    keyword,
+                    ident
+                );
+            }
+        }
+
+        let mut columns = vec![input.parse()?];
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            columns.push(input.parse()?);
+        }
+
+        Ok(Self { columns })
+    }
+}
@@ -0,0 +1,150 @@
+use crate::types::{peek_keyword, sql_text};
+use proc_macro_error2::abort;
+#[cfg(feature = "try-parse")]
+use proc_macro_error2::emit_error;
+use proc_macro2::{Ident, TokenStream as TokenStream2, TokenTree};
+use syn::Token;
+use syn::parse::{Parse, ParseStream};
+
+/// Keywords that can not be used as a table alias, since they start the next clause.
+const RESERVED: &[&str] = &["WHERE", "JOIN", "INNER", "LEFT", "ON"];
+
+/// Parses the identifier `keyword`, ignoring case.
+fn parse_keyword(input: ParseStream, keyword: &str) -> syn::Result<Ident> {
+    let ident = input.parse::<Ident>()?;
+
+    if !ident.to_string().eq_ignore_ascii_case(keyword) {
+        return Err(syn::Error::new(
+            ident.span(),
+            format!("Expected `{}`, found `{}`", keyword, ident),
+        ));
+    }
+
+    Ok(ident)
+}
+
+/// Parses the optional alias of a table, written as `table alias` or `table AS alias`.
+pub(crate) fn parse_table_alias(input: ParseStream) -> syn::Result<Option<Ident>> {
+    if input.peek(Token![as]) {
+        input.parse::<Token![as]>()?;
+        return Ok(Some(input.parse()?));
+    }
+
+    if peek_keyword(input, "AS") {
+        input.parse::<Ident>()?;
+        return Ok(Some(input.parse()?));
+    }
+
+    if RESERVED.iter().any(|keyword| peek_keyword(input, keyword)) {
+        return Ok(None);
+    }
+
+    if input.peek(syn::Ident) {
+        Ok(Some(input.parse()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// The kind of a [`Join`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) enum JoinKind {
+    Inner,
+    Left,
+}
+
+/// Represents a `JOIN` clause of a filter definition.
+///
+/// # Parsing
+///
+/// Parses input in the format:
+/// ```ignore
+/// [INNER | LEFT [OUTER]] JOIN table [[AS] alias] ON condition
+/// ```
+///
+/// The `ON` condition is written into the query as is, up to the next `JOIN` or `WHERE`. It can
+/// only compare columns, as it has no fields of its own, and string literals are written as raw
+/// SQL like everywhere else in the filter, so `s.state != "'cancelled'"` compares against the
+/// text `cancelled`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Join {
+    pub(crate) kind: JoinKind,
+    pub(crate) table_name: Ident,
+    pub(crate) alias: Option<Ident>,
+    pub(crate) on: String,
+}
+
+impl Join {
+    /// Whether the input continues with a `JOIN` clause.
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        ["JOIN", "INNER", "LEFT"]
+            .iter()
+            .any(|keyword| peek_keyword(input, keyword))
+    }
+
+    /// Gets the clause as written in SQL.
+    pub(crate) fn sql(&self) -> String {
+        let kind = match self.kind {
+            JoinKind::Inner => "INNER JOIN",
+            JoinKind::Left => "LEFT JOIN",
+        };
+
+        match &self.alias {
+            Some(alias) => format!("{} {} {} ON {}", kind, self.table_name, alias, self.on),
+            None => format!("{} {} ON {}", kind, self.table_name, self.on),
+        }
+    }
+}
+
+impl Parse for Join {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kind = if peek_keyword(input, "LEFT") {
+            input.parse::<Ident>()?;
+
+            if peek_keyword(input, "OUTER") {
+                input.parse::<Ident>()?;
+            }
+
+            JoinKind::Left
+        } else {
+            if peek_keyword(input, "INNER") {
+                input.parse::<Ident>()?;
+            }
+
+            JoinKind::Inner
+        };
+
+        parse_keyword(input, "JOIN")?;
+
+        let table_name: Ident = input.parse()?;
+        let alias = parse_table_alias(input)?;
+
+        let on = parse_keyword(input, "ON").unwrap_or_else(|err| {
+            abort!(
+                err.span(), "Expected `ON` after the joined table `{}`", table_name;
+                help = "Every `JOIN` needs a condition, like `JOIN customers c ON c.id = o.customer_id`"
+            );
+        });
+
+        let mut tokens = TokenStream2::new();
+
+        while !input.is_empty() && !Self::peek(input) && !peek_keyword(input, "WHERE") {
+            tokens.extend([input.parse::<TokenTree>()?]);
+        }
+
+        if tokens.is_empty() {
+            #[cfg(not(feature = "try-parse"))]
+            abort!(on, "Expected a condition after `ON`");
+
+            #[cfg(feature = "try-parse")]
+            emit_error!(on, "Expected a condition after `ON`");
+        }
+
+        Ok(Self {
+            kind,
+            table_name,
+            alias,
+            on: sql_text(tokens),
+        })
+    }
+}
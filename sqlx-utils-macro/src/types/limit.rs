@@ -0,0 +1,82 @@
+use crate::types::columns::ColumnVal;
+use crate::types::peek_keyword;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::Token;
+use syn::parse::ParseStream;
+
+/// Represents the `LIMIT` or `OFFSET` clause of a filter definition.
+///
+/// # Parsing
+///
+/// Parses input in the format:
+/// ```ignore
+/// [?]LIMIT type
+/// [?]OFFSET type
+/// ```
+///
+/// # Code Generation
+///
+/// Expands to a `limit` or `offset` field like a condition, optional if prefixed with `?`, and
+/// the `row_limit` or `row_offset` method of the `SqlFilter` implementation. The value is bound
+/// by the repository, so the type must convert into a `u64`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct RowLimit {
+    pub(crate) field: Ident,
+    pub(crate) column_type: ColumnVal,
+    pub(crate) optional: bool,
+}
+
+impl RowLimit {
+    /// Parses the clause starting with `keyword`, if the input continues with it.
+    pub(crate) fn parse_clause(input: ParseStream, keyword: &str) -> syn::Result<Option<Self>> {
+        let fork = input.fork();
+        let optional = fork.peek(Token![?]);
+
+        if optional {
+            fork.parse::<Token![?]>()?;
+        }
+
+        if !peek_keyword(&fork, keyword) {
+            return Ok(None);
+        }
+
+        if optional {
+            input.parse::<Token![?]>()?;
+        }
+
+        let keyword: Ident = input.parse()?;
+        let field = format_ident!(
+            "{}",
+            keyword.to_string().to_lowercase(),
+            span = keyword.span()
+        );
+
+        let column_type = match input.parse()? {
+            ColumnVal::Raw(lit) => {
+                proc_macro_error2::abort!(
+                    lit, "`{}` takes a Rust type", keyword;
+                    help = "Use an unsigned integer type like `u32`, it is bound as a value"
+                );
+            }
+            ty => ty,
+        };
+
+        Ok(Some(Self {
+            field,
+            column_type,
+            optional,
+        }))
+    }
+
+    /// Expands the value of the clause for the `row_limit` and `row_offset` methods.
+    pub(crate) fn expand_value(&self) -> TokenStream2 {
+        let field = &self.field;
+
+        if self.optional {
+            quote! { self.#field.map(::core::primitive::u64::from) }
+        } else {
+            quote! { ::core::option::Option::Some(::core::primitive::u64::from(self.#field)) }
+        }
+    }
+}
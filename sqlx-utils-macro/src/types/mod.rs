@@ -1,12 +1,18 @@
 use crate::CRATE_NAME_STR;
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Delimiter, Ident, Spacing, Span, TokenStream, TokenTree};
 use quote::quote;
+use syn::LitStr;
+use syn::parse::ParseStream;
 
 pub(crate) mod columns;
 pub(crate) mod condition;
 pub(crate) mod expression;
 pub(crate) mod filter_sql;
 pub(crate) mod filter_table;
+pub(crate) mod group_by;
+pub(crate) mod join;
+pub(crate) mod limit;
+pub(crate) mod order_by;
 pub(crate) mod sql_operator;
 
 /// Gets the crate name as an [`Ident`].
@@ -21,6 +27,66 @@ fn database_type() -> TokenStream {
     quote! { ::sqlx_utils::prelude::Database }
 }
 
+/// Gets the bind parameter placeholder for the `index`'th argument of a query, starting at 1.
+///
+/// Postgres uses numbered placeholders (`$1`), every other database, including `Any`, uses `?`.
+#[inline]
+pub(crate) fn placeholder(index: usize) -> String {
+    if cfg!(all(
+        feature = "postgres",
+        not(any(feature = "sqlite", feature = "mysql", feature = "any"))
+    )) {
+        format!("${index}")
+    } else {
+        String::from("?")
+    }
+}
+
+/// Whether the next token is the identifier `keyword`, ignoring case.
+pub(crate) fn peek_keyword(input: ParseStream, keyword: &str) -> bool {
+    input
+        .fork()
+        .parse::<Ident>()
+        .is_ok_and(|ident| ident.to_string().eq_ignore_ascii_case(keyword))
+}
+
+/// Writes `tokens` back as SQL, keeping qualified columns like `c.id` and operators like `>=`
+/// together and writing string literals as raw SQL.
+pub(crate) fn sql_text(tokens: TokenStream) -> String {
+    let mut sql = String::new();
+    let mut space = false;
+
+    for token in tokens {
+        let (text, space_after) = match &token {
+            TokenTree::Punct(punct) if punct.as_char() == '.' => {
+                space = false;
+                (String::from("."), false)
+            }
+            TokenTree::Punct(punct) => (
+                punct.as_char().to_string(),
+                punct.spacing() == Spacing::Alone,
+            ),
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                (format!("({})", sql_text(group.stream())), true)
+            }
+            TokenTree::Literal(_) => match syn::parse2::<LitStr>(token.clone().into()) {
+                Ok(lit) => (lit.value(), true),
+                Err(_) => (token.to_string(), true),
+            },
+            _ => (token.to_string(), true),
+        };
+
+        if space {
+            sql.push(' ');
+        }
+
+        sql.push_str(&text);
+        space = space_after;
+    }
+
+    sql
+}
+
 /*impl SqlOperator {
     fn as_str(&self) -> &'static str {
         match self {
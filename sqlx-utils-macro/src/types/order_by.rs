@@ -0,0 +1,188 @@
+use crate::types::columns::QualifiedColumn;
+use proc_macro_error2::abort;
+#[cfg(feature = "try-parse")]
+use proc_macro_error2::emit_error;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Token, Visibility};
+
+/// Represents the `ORDER BY` clause of a filter definition.
+///
+/// The clause declares the columns the caller may order on, the columns themselves are only
+/// written into the query when picked with the generated `order_by` builder.
+///
+/// # Parsing
+///
+/// Parses input in the format:
+/// ```ignore
+/// ORDER BY ?[table.]column [as VariantName], ...
+/// ```
+///
+/// # Code Generation
+///
+/// Expands to:
+/// - A `{Name}Sort` enum with a variant for each column, `Filter` is stripped from the end of the
+///   filter name so `UserFilter` generates `UserSort`
+/// - An `order_by` builder method on the filter struct
+/// - The `ordering` method of the `SqlFilter` implementation
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct OrderBy {
+    pub(crate) columns: Vec<OrderColumn>,
+}
+
+/// A single sortable column in the `ORDER BY` clause.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct OrderColumn {
+    pub(crate) column: QualifiedColumn,
+    pub(crate) variant: Ident,
+}
+
+impl OrderBy {
+    /// Gets the name of the generated sort enum for the filter `name`.
+    pub(crate) fn sort_ident(name: &Ident) -> Ident {
+        let name_str = name.to_string();
+
+        match name_str.strip_suffix("Filter") {
+            Some(base) if !base.is_empty() => format_ident!("{}Sort", base),
+            _ => format_ident!("{}Sort", name),
+        }
+    }
+
+    /// Expands the sort enum for the filter `name`.
+    pub(crate) fn expand_sort_enum(&self, vis: &Visibility, name: &Ident) -> TokenStream2 {
+        let sort = Self::sort_ident(name);
+
+        let variants = self.columns.iter().map(|column| &column.variant);
+
+        let column_arms = self.columns.iter().map(|column| {
+            let variant = &column.variant;
+            let column_name = column.column.sql();
+
+            quote! { Self::#variant => #column_name }
+        });
+
+        let doc = format!("The columns a [`{name}`] can be ordered on.");
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #vis enum #sort {
+                #(#variants,)*
+            }
+
+            impl #sort {
+                /// Gets the name of the column.
+                #[inline]
+                #vis fn column(&self) -> &'static str {
+                    match self {
+                        #(#column_arms,)*
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Parse for OrderBy {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        for keyword in ["ORDER", "BY"] {
+            let ident = input.parse::<Ident>()?;
+
+            if !ident.to_string().eq_ignore_ascii_case(keyword) {
+                abort!(
+                    ident,
+                    "Expected `{}` but instead found `{}`",
+                    keyword,
+                    ident
+                );
+            }
+        }
+
+        let mut columns = vec![input.parse::<OrderColumn>()?];
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            columns.push(input.parse()?);
+        }
+
+        let mut seen: Vec<&OrderColumn> = Vec::with_capacity(columns.len());
+
+        for column in &columns {
+            if let Some(prev) = seen.iter().find(|prev| {
+                prev.column.sql() == column.column.sql() || prev.variant == column.variant
+            }) {
+                #[cfg(not(feature = "try-parse"))]
+                abort!(
+                    column.column.column,
+                    "Column `{}` is declared more than once in `ORDER BY`",
+                    prev.column.sql()
+                );
+
+                #[cfg(feature = "try-parse")]
+                emit_error!(
+                    column.column.column,
+                    "Column `{}` is declared more than once in `ORDER BY`",
+                    prev.column.sql()
+                );
+            }
+
+            seen.push(column);
+        }
+
+        Ok(Self { columns })
+    }
+}
+
+impl Parse for OrderColumn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![?]) {
+            input.parse::<Token![?]>()?;
+        } else {
+            let span = input.span();
+
+            #[cfg(not(feature = "try-parse"))]
+            abort!(
+                span, "Expected a sortable column starting with `?`";
+                help = "Columns in `ORDER BY` are only ordered on when picked with `order_by`, declare them as `?column`"
+            );
+
+            #[cfg(feature = "try-parse")]
+            emit_error!(
+                span, "Expected a sortable column starting with `?`";
+                help = "Columns in `ORDER BY` are only ordered on when picked with `order_by`, declare them as `?column`"
+            );
+        }
+
+        let column: QualifiedColumn = input.parse()?;
+
+        // Qualified columns keep their table in the variant, so `c.name` becomes `CName`
+        let variant = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            input.parse()?
+        } else {
+            format_ident!(
+                "{}",
+                pascal_case(&column.field.to_string()),
+                span = column.column.span()
+            )
+        };
+
+        Ok(Self { column, variant })
+    }
+}
+
+/// Converts a `snake_case` column name to `PascalCase`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -22,6 +22,9 @@ use syn::parse::{Parse, ParseStream};
 /// - `ILike`: `ILIKE`
 /// - `In`: `IN`
 /// - `NotIn`: `NOT IN`
+/// - `IsNull`: `IS NULL`
+/// - `IsNotNull`: `IS NOT NULL`
+/// - `Between`: `BETWEEN`
 ///
 /// # Parsing
 ///
@@ -41,6 +44,9 @@ use syn::parse::{Parse, ParseStream};
 /// - `ILIKE` → `i_like`
 /// - `IN` → `in_values`
 /// - `NOT IN` → `not_in_values`
+/// - `IS NULL` → `is_null`
+/// - `IS NOT NULL` → `is_not_null`
+/// - `BETWEEN` → `between`
 #[derive(Copy, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) enum SqlOperator {
@@ -54,6 +60,9 @@ pub(crate) enum SqlOperator {
     ILike,
     In,
     NotIn,
+    IsNull,
+    IsNotNull,
+    Between,
 }
 
 impl SqlOperator {
@@ -67,9 +76,49 @@ impl SqlOperator {
     /// - `ILIKE` → `i_like`
     /// - `IN` → `in_values`
     /// - `NOT IN` → `not_in_values`
-    pub(crate) const SUPPORTED: [&'static str; 10] = [
-        "=", "!=", ">", "<", ">=", "<=", "LIKE", "ILIKE", "IN", "NOT IN",
+    /// - `IS NULL` → `is_null`
+    /// - `IS NOT NULL` → `is_not_null`
+    /// - `BETWEEN` → `between`
+    pub(crate) const SUPPORTED: [&'static str; 13] = [
+        "=",
+        "!=",
+        ">",
+        "<",
+        ">=",
+        "<=",
+        "LIKE",
+        "ILIKE",
+        "IN",
+        "NOT IN",
+        "IS NULL",
+        "IS NOT NULL",
+        "BETWEEN",
     ];
+
+    /// The suffix added to the column name to get the key of a field when deserializing a
+    /// filter, `=` has none so `?name = String` is read from `name`.
+    pub(crate) fn serde_suffix(&self) -> &'static str {
+        match self {
+            SqlOperator::Equals => "",
+            SqlOperator::NotEquals => "_ne",
+            SqlOperator::GreaterThan => "_gt",
+            SqlOperator::LessThan => "_lt",
+            SqlOperator::GreaterThanOrEqual => "_gte",
+            SqlOperator::LessThanOrEqual => "_lte",
+            SqlOperator::Like => "_like",
+            SqlOperator::ILike => "_ilike",
+            SqlOperator::In => "_in",
+            SqlOperator::NotIn => "_not_in",
+            SqlOperator::IsNull => "_is_null",
+            SqlOperator::IsNotNull => "_is_not_null",
+            SqlOperator::Between => "_between",
+        }
+    }
+
+    /// Whether the operator compares against no value, like `IS NULL`.
+    pub(crate) fn is_unary(&self) -> bool {
+        matches!(self, SqlOperator::IsNull | SqlOperator::IsNotNull)
+    }
 }
 
 impl Parse for SqlOperator {
@@ -101,6 +150,24 @@ impl Parse for SqlOperator {
                 "LIKE" => Ok(SqlOperator::Like),
                 "ILIKE" => Ok(SqlOperator::ILike),
                 "IN" => Ok(SqlOperator::In),
+                "BETWEEN" => Ok(SqlOperator::Between),
+                "IS" => {
+                    let mut next: Ident = input.parse()?;
+                    let negated = next.to_string().eq_ignore_ascii_case("NOT");
+
+                    if negated {
+                        next = input.parse()?;
+                    }
+
+                    match (next.to_string().eq_ignore_ascii_case("NULL"), negated) {
+                        (true, false) => Ok(SqlOperator::IsNull),
+                        (true, true) => Ok(SqlOperator::IsNotNull),
+                        (false, _) => Err(syn::Error::new(
+                            next.span(),
+                            format!("Expected `NULL` after `IS`, found `{}`", next),
+                        )),
+                    }
+                }
                 "NOT" => {
                     if input.peek(Token![=]) {
                         input.parse::<Token![=]>()?;
@@ -163,6 +230,9 @@ impl ToTokens for SqlOperator {
             SqlOperator::ILike => quote! {::#crate_name::filter::i_like},
             SqlOperator::In => quote! {::#crate_name::filter::in_values},
             SqlOperator::NotIn => quote! {::#crate_name::filter::not_in_values},
+            SqlOperator::IsNull => quote! {::#crate_name::filter::is_null},
+            SqlOperator::IsNotNull => quote! {::#crate_name::filter::is_not_null},
+            SqlOperator::Between => quote! {::#crate_name::filter::between},
         }
         .to_tokens(tokens)
     }
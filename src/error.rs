@@ -11,6 +11,46 @@ pub enum Error {
     Sqlx(#[from] sqlx::Error),
     #[error("Failed to acquire lock on mutex")]
     MutexLockError,
+    #[error("The transaction has already been committed or rolled back")]
+    TransactionClosed,
     #[error(transparent)]
     Boxed(Box<dyn std::error::Error + Send>),
 }
+
+/// Errors that may go away when the transaction that failed is run again.
+///
+/// This is used by [`with_retrying_transaction`](crate::traits::TransactionRepository::with_retrying_transaction)
+/// to decide whether to run a transaction again. Custom error types wrapping [`sqlx::Error`]
+/// implement it by asking the wrapped error.
+pub trait RetryableError {
+    /// Whether the error is a serialization failure, a deadlock or a busy database.
+    fn is_retryable(&self) -> bool;
+}
+
+impl RetryableError for sqlx::Error {
+    /// Checks the code of database errors for:
+    ///
+    /// * `40001`: A serialization failure on Postgres, or a deadlock (1213) on MySQL
+    /// * `40P01`: A deadlock on Postgres
+    /// * `SQLITE_BUSY` and its extended codes on SQLite
+    fn is_retryable(&self) -> bool {
+        let Self::Database(err) = self else {
+            return false;
+        };
+
+        // SQLite codes are the extended result codes SQLITE_BUSY, SQLITE_BUSY_RECOVERY,
+        // SQLITE_BUSY_SNAPSHOT and SQLITE_BUSY_TIMEOUT
+        err.code()
+            .is_some_and(|code| matches!(&*code, "40001" | "40P01" | "5" | "261" | "517" | "773"))
+    }
+}
+
+impl RetryableError for Error {
+    #[inline]
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Sqlx(err) => err.is_retryable(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::filter::Filter;
+use crate::sql_delimiter;
+use crate::traits::{DynFilter, SqlFilter};
+use crate::types::{Database, Direction};
+use sqlx::QueryBuilder;
+use std::fmt::{Debug, Formatter};
+
+/// A filter with its type erased, for filters composed at runtime.
+///
+/// Combining filters with [`and`](Filter::and) and [`or`](Filter::or) nests their types, so a
+/// filter built in a loop or from user input can not be named. Boxing every part gives them all
+/// the same type, which can then be stored in a [`Vec`] and combined with [`any_of`] and
+/// [`all_of`].
+///
+/// The lifetime `'a` is how long the boxed filter may borrow for, the filter itself must be
+/// usable with any arguments lifetime like the filters passed to the repositories.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::filter::{BoxedFilter, all_of, any_of, equals, like};
+/// fn user_filter(name: Option<String>, ids: Vec<i64>) -> BoxedFilter<'static> {
+///     let mut filters = Vec::new();
+///
+///     if let Some(name) = name {
+///         filters.push(like("name", Some(name)).boxed());
+///     }
+///
+///     filters.push(any_of(ids.into_iter().map(|id| equals("id", Some(id)))).boxed());
+///
+///     // (name LIKE ? AND (id = ? OR id = ?))
+///     all_of(filters).boxed()
+/// }
+/// ```
+pub struct BoxedFilter<'a>(Box<dyn for<'args> DynFilter<'args> + Send + 'a>);
+
+impl<'a> BoxedFilter<'a> {
+    /// Boxes `filter`, erasing its type.
+    #[inline]
+    pub fn new<F>(filter: F) -> Self
+    where
+        F: for<'args> SqlFilter<'args> + Send + 'a,
+    {
+        Self(Box::new(filter))
+    }
+}
+
+impl<'args> SqlFilter<'args> for BoxedFilter<'_> {
+    #[inline]
+    fn apply_filter(self, builder: &mut QueryBuilder<'args, Database>) {
+        self.0.apply_boxed_filter(builder)
+    }
+
+    #[inline]
+    fn should_apply_filter(&self) -> bool {
+        self.0.should_apply_boxed_filter()
+    }
+
+    #[inline]
+    fn ordering(&self) -> Vec<(&'static str, Direction)> {
+        self.0.boxed_ordering()
+    }
+
+    #[inline]
+    fn apply_clauses(self, builder: &mut QueryBuilder<'args, Database>) {
+        self.0.apply_boxed_clauses(builder)
+    }
+
+    #[inline]
+    fn groups_rows(&self) -> bool {
+        self.0.boxed_groups_rows()
+    }
+
+    #[inline]
+    fn row_limit(&self) -> Option<u64> {
+        self.0.boxed_row_limit()
+    }
+
+    #[inline]
+    fn row_offset(&self) -> Option<u64> {
+        self.0.boxed_row_offset()
+    }
+}
+
+impl Debug for BoxedFilter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BoxedFilter").finish_non_exhaustive()
+    }
+}
+
+/// Applies the filters that should apply joined by `separator`, wrapped in parentheses when
+/// there is more than one.
+fn push_joined<'args, F>(
+    filters: Vec<F>,
+    separator: &str,
+    builder: &mut QueryBuilder<'args, Database>,
+) where
+    F: SqlFilter<'args>,
+{
+    let filters = filters
+        .into_iter()
+        .filter(SqlFilter::should_apply_filter)
+        .collect::<Vec<_>>();
+
+    let grouped = filters.len() > 1;
+
+    if grouped {
+        builder.push("(");
+    }
+
+    for (idx, filter) in filters.into_iter().enumerate() {
+        if idx > 0 {
+            builder.push(separator);
+        }
+
+        filter.apply_filter(builder);
+    }
+
+    if grouped {
+        builder.push(")");
+    }
+}
+
+sql_delimiter! {
+    /// Matches rows matching any of the filters.
+    ///
+    /// Filters that do not apply are skipped, and when none apply neither does this filter, so
+    /// an empty list matches every row like any other filter without conditions.
+    pub struct AnyOf<F> {
+        pub filters: Vec<F>
+    }
+
+    apply_filter(s, builder) {
+        push_joined(s.filters, " OR ", builder)
+    }
+
+    should_apply_filter(s) {
+        s.filters.iter().any(SqlFilter::should_apply_filter)
+    }
+
+    ordering(s) {
+        s.filters.iter().flat_map(SqlFilter::ordering).collect()
+    }
+}
+
+sql_delimiter! {
+    /// Matches rows matching all of the filters.
+    ///
+    /// Filters that do not apply are skipped, and when none apply neither does this filter.
+    pub struct AllOf<F> {
+        pub filters: Vec<F>
+    }
+
+    apply_filter(s, builder) {
+        push_joined(s.filters, " AND ", builder)
+    }
+
+    should_apply_filter(s) {
+        s.filters.iter().any(SqlFilter::should_apply_filter)
+    }
+
+    ordering(s) {
+        s.filters.iter().flat_map(SqlFilter::ordering).collect()
+    }
+}
+
+/// Combines `filters` with `OR` into a single group, like `(a OR b OR c)`.
+///
+/// Use [`BoxedFilter`] to combine filters of different types.
+#[inline]
+pub fn any_of<F>(filters: impl IntoIterator<Item = F>) -> Filter<AnyOf<F>> {
+    Filter::new(AnyOf::new(filters.into_iter().collect()))
+}
+
+/// Combines `filters` with `AND` into a single group, like `(a AND b AND c)`.
+///
+/// Use [`BoxedFilter`] to combine filters of different types.
+#[inline]
+pub fn all_of<F>(filters: impl IntoIterator<Item = F>) -> Filter<AllOf<F>> {
+    Filter::new(AllOf::new(filters.into_iter().collect()))
+}
@@ -0,0 +1,188 @@
+use crate::types::{Database, Dialect};
+use sqlx::QueryBuilder;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// The longest identifier every backend accepts, Postgres cuts longer names to this length.
+const MAX_IDENTIFIER_LEN: usize = 63;
+
+/// The dialect quoting identifiers with the `"` of ANSI SQL, for queries built without a known
+/// dialect.
+const ANSI_QUOTING: Dialect = Dialect::Postgres;
+
+/// A column name checked to be a valid identifier, optionally qualified with its table like
+/// `users.name` or `public.users.name`.
+///
+/// Every part of the name must start with an ASCII letter or `_`, followed by ASCII letters,
+/// digits or `_`, and be at most 63 bytes long. Filters quote every part for the database they
+/// are written for, so `users.name` becomes `"users"."name"` on Postgres and SQLite and
+/// `` `users`.`name` `` on MySQL, see [`Dialect::current`] for how the database is picked.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::filter::{Identifier, equals};
+/// # use sqlx_utils::types::Dialect;
+/// // Checked at compile time when used in a constant
+/// const NAME: Identifier = Identifier::new("users.name");
+///
+/// assert_eq!(NAME.quoted(Dialect::MySql), "`users`.`name`");
+/// assert!(Identifier::try_new("name; DROP TABLE users").is_err());
+///
+/// // Names read at runtime are kept as owned strings
+/// let column = String::from("email");
+/// assert_eq!(Identifier::try_new(column)?.as_str(), "email");
+///
+/// let filter = equals(NAME, Some("Alice"));
+/// # Ok::<(), sqlx_utils::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identifier(Cow<'static, str>);
+
+impl Identifier {
+    /// Creates an identifier from `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid identifier, which is a compile error when used in a
+    /// constant. Use [`try_new`](Identifier::try_new) for names not known at compile time.
+    #[inline]
+    pub const fn new(name: &'static str) -> Self {
+        if !Self::is_valid(name) {
+            panic!("Invalid identifier, expected a name like `column` or `table.column`");
+        }
+
+        Self(Cow::Borrowed(name))
+    }
+
+    /// Creates an identifier from `name`, which can be a `&'static str` or a [`String`] read at
+    /// runtime, failing if it is not a valid identifier.
+    pub fn try_new(name: impl Into<Cow<'static, str>>) -> crate::Result<Self> {
+        let name = name.into();
+
+        if Self::is_valid(&name) {
+            Ok(Self(name))
+        } else {
+            Err(crate::Error::Repository {
+                message: format!("`{name}` is not a valid identifier").into(),
+            })
+        }
+    }
+
+    /// Checks that `name` is made of one to three valid parts separated by `.`.
+    const fn is_valid(name: &str) -> bool {
+        let bytes = name.as_bytes();
+        let mut parts = 1;
+        let mut part_len = 0;
+        let mut idx = 0;
+
+        while idx < bytes.len() {
+            let byte = bytes[idx];
+
+            if byte == b'.' {
+                if part_len == 0 {
+                    return false;
+                }
+
+                parts += 1;
+                part_len = 0;
+            } else if byte == b'_'
+                || byte.is_ascii_alphabetic()
+                || (part_len > 0 && byte.is_ascii_digit())
+            {
+                part_len += 1;
+            } else {
+                return false;
+            }
+
+            if parts > 3 || part_len > MAX_IDENTIFIER_LEN {
+                return false;
+            }
+
+            idx += 1;
+        }
+
+        part_len > 0
+    }
+
+    /// Gets the name as given.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Gets the name with every part quoted for `dialect`.
+    pub fn quoted(&self, dialect: Dialect) -> String {
+        quote_parts(&self.0, dialect)
+    }
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Quotes every part of the checked identifier `name` for `dialect`.
+fn quote_parts(name: &str, dialect: Dialect) -> String {
+    name.split('.')
+        .map(|part| dialect.quote_identifier(part))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// The column a filter condition is on.
+///
+/// A plain `&'static str` is written into the query as is, which allows expressions like
+/// `LOWER(name)`, while an [`Identifier`] is checked and quoted. Every filter function taking a
+/// column accepts both.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Column {
+    name: Cow<'static, str>,
+    quoted: bool,
+}
+
+impl Column {
+    /// Gets the column as given, without any quotes.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Pushes the column, quoted for [`Dialect::current`] if it is an [`Identifier`].
+    ///
+    /// Without a known dialect the identifier is quoted with the standard `"` of ANSI SQL, which
+    /// every database but MySQL understands. The repositories always build their queries for the
+    /// dialect of their own pool.
+    ///
+    /// Public for the exported filter macros, like [`sql_operator!`](crate::sql_operator).
+    #[doc(hidden)]
+    pub fn push(&self, builder: &mut QueryBuilder<'_, Database>) {
+        if self.quoted {
+            let dialect = Dialect::current().unwrap_or(ANSI_QUOTING);
+            builder.push(quote_parts(&self.name, dialect));
+        } else {
+            builder.push(&self.name);
+        }
+    }
+}
+
+impl From<&'static str> for Column {
+    #[inline]
+    fn from(name: &'static str) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            quoted: false,
+        }
+    }
+}
+
+impl From<Identifier> for Column {
+    #[inline]
+    fn from(ident: Identifier) -> Self {
+        Self {
+            name: ident.0,
+            quoted: true,
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use crate::filter::{BoxedFilter, Filter, all_of, any_of};
+use crate::traits::SqlFilter;
+use crate::types::{CursorValue, Database};
+use serde::Deserialize;
+use serde_json::Value;
+use sqlx::QueryBuilder;
+
+/// A filter read from JSON, for filters picked by the client like in a search endpoint.
+///
+/// The fields in the JSON are never written into the query as is, every field must be allowed
+/// in a [`ColumnWhitelist`] which maps it to a column and the kind of value it accepts before
+/// the filter can be turned into a [`SqlFilter`] with [`into_filter`](JsonFilter::into_filter).
+///
+/// # Format
+///
+/// ```json
+/// {"and": [
+///     {"field": "age", "op": ">=", "value": 18},
+///     {"or": [
+///         {"field": "name", "op": "like", "value": "A%"},
+///         {"not": {"field": "deleted_at", "op": "is_null"}}
+///     ]}
+/// ]}
+/// ```
+///
+/// The operators are `=`, `!=`, `>`, `<`, `>=`, `<=`, `like`, `ilike`, `in`, `not_in`,
+/// `is_null`, `is_not_null` and `between`, with the keyword operators also accepted in upper
+/// case. `in` and `not_in` take a non empty array, `between` an array of the two bounds and the
+/// null checks take no value or a boolean, where `false` checks the opposite.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::filter::{ColumnWhitelist, JsonFilter, ValueKind};
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let whitelist = ColumnWhitelist::new()
+///     .column("age", ValueKind::Int)
+///     .column_as("name", "user_name", ValueKind::Text);
+///
+/// let json = r#"{"and": [{"field": "age", "op": ">=", "value": 18}, {"field": "name", "op": "like", "value": "A%"}]}"#;
+///
+/// // (age >= ? AND user_name LIKE ?)
+/// let filter = serde_json::from_str::<JsonFilter>(json)?.into_filter(&whitelist)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum JsonFilter {
+    /// Matches rows matching all of the filters.
+    And { and: Vec<JsonFilter> },
+    /// Matches rows matching any of the filters.
+    Or { or: Vec<JsonFilter> },
+    /// Matches rows not matching the filter.
+    Not { not: Box<JsonFilter> },
+    /// Compares the column of `field` against `value` with `op`.
+    Condition {
+        field: String,
+        op: JsonOperator,
+        #[serde(default)]
+        value: Value,
+    },
+}
+
+/// An operator in a [`JsonFilter`] condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum JsonOperator {
+    #[serde(rename = "=")]
+    Equals,
+    #[serde(rename = "!=")]
+    NotEquals,
+    #[serde(rename = ">")]
+    GreaterThan,
+    #[serde(rename = "<")]
+    LessThan,
+    #[serde(rename = ">=")]
+    GreaterThanOrEqual,
+    #[serde(rename = "<=")]
+    LessThanOrEqual,
+    #[serde(rename = "like", alias = "LIKE")]
+    Like,
+    #[serde(rename = "ilike", alias = "ILIKE")]
+    ILike,
+    #[serde(rename = "in", alias = "IN")]
+    In,
+    #[serde(rename = "not_in", alias = "NOT IN")]
+    NotIn,
+    #[serde(rename = "is_null", alias = "IS NULL")]
+    IsNull,
+    #[serde(rename = "is_not_null", alias = "IS NOT NULL")]
+    IsNotNull,
+    #[serde(rename = "between", alias = "BETWEEN")]
+    Between,
+}
+
+impl JsonOperator {
+    /// Gets the SQL of the operator.
+    #[inline]
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Equals => "=",
+            Self::NotEquals => "!=",
+            Self::GreaterThan => ">",
+            Self::LessThan => "<",
+            Self::GreaterThanOrEqual => ">=",
+            Self::LessThanOrEqual => "<=",
+            Self::Like => "LIKE",
+            Self::ILike => "ILIKE",
+            Self::In => "IN",
+            Self::NotIn => "NOT IN",
+            Self::IsNull => "IS NULL",
+            Self::IsNotNull => "IS NOT NULL",
+            Self::Between => "BETWEEN",
+        }
+    }
+}
+
+/// The kind of value a column accepts in a [`JsonFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    Int,
+    Float,
+    Text,
+    Bool,
+}
+
+/// The fields a [`JsonFilter`] may filter on, and the columns they map to.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnWhitelist {
+    columns: Vec<(&'static str, &'static str, ValueKind)>,
+}
+
+impl ColumnWhitelist {
+    /// Creates a whitelist without any columns.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows filtering on `column`, using the column name as the field name.
+    #[inline]
+    pub fn column(self, column: &'static str, kind: ValueKind) -> Self {
+        self.column_as(column, column, kind)
+    }
+
+    /// Allows filtering on `column` through the field `field`.
+    #[inline]
+    pub fn column_as(mut self, field: &'static str, column: &'static str, kind: ValueKind) -> Self {
+        self.columns.push((field, column, kind));
+        self
+    }
+
+    fn get(&self, field: &str) -> Option<(&'static str, ValueKind)> {
+        self.columns
+            .iter()
+            .find(|(name, _, _)| *name == field)
+            .map(|(_, column, kind)| (*column, *kind))
+    }
+}
+
+impl JsonFilter {
+    /// Validates the filter against `whitelist` and turns it into a [`SqlFilter`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if a field is not in the whitelist, a value does not match the kind of its column
+    /// or the operator, `like` is used on a column that is not text, or an `and` or `or` has no
+    /// conditions, which would match every row instead of none for an empty `or`.
+    pub fn into_filter(self, whitelist: &ColumnWhitelist) -> crate::Result<BoxedFilter<'static>> {
+        match self {
+            Self::And { and } => Ok(all_of(Self::into_filters("and", and, whitelist)?).boxed()),
+            Self::Or { or } => Ok(any_of(Self::into_filters("or", or, whitelist)?).boxed()),
+            Self::Not { not } => Ok(Filter::new(not.into_filter(whitelist)?).not().boxed()),
+            Self::Condition { field, op, value } => {
+                let (column, kind) = whitelist.get(&field).ok_or_else(|| {
+                    invalid(format!("Filtering on the field `{field}` is not allowed"))
+                })?;
+
+                Ok(BoxedFilter::new(JsonCondition::new(
+                    &field, column, kind, op, value,
+                )?))
+            }
+        }
+    }
+
+    fn into_filters(
+        group: &str,
+        filters: Vec<Self>,
+        whitelist: &ColumnWhitelist,
+    ) -> crate::Result<Vec<BoxedFilter<'static>>> {
+        if filters.is_empty() {
+            return Err(invalid(format!("`{group}` needs at least one condition")));
+        }
+
+        filters
+            .into_iter()
+            .map(|filter| filter.into_filter(whitelist))
+            .collect()
+    }
+}
+
+fn invalid(message: String) -> crate::Error {
+    crate::Error::Repository {
+        message: message.into(),
+    }
+}
+
+/// A single validated condition of a [`JsonFilter`].
+struct JsonCondition {
+    column: &'static str,
+    op: JsonOperator,
+    values: Vec<CursorValue>,
+}
+
+impl JsonCondition {
+    fn new(
+        field: &str,
+        column: &'static str,
+        kind: ValueKind,
+        op: JsonOperator,
+        value: Value,
+    ) -> crate::Result<Self> {
+        let read = |value: Value| read_value(field, kind, value);
+
+        let (op, values) = match op {
+            JsonOperator::IsNull | JsonOperator::IsNotNull => {
+                let check = match value {
+                    Value::Null => true,
+                    Value::Bool(check) => check,
+                    _ => {
+                        return Err(invalid(format!(
+                            "`{}` on `{field}` takes no value or a boolean",
+                            op.as_sql()
+                        )));
+                    }
+                };
+
+                let op = match (op, check) {
+                    (JsonOperator::IsNull, false) => JsonOperator::IsNotNull,
+                    (JsonOperator::IsNotNull, false) => JsonOperator::IsNull,
+                    (op, _) => op,
+                };
+
+                (op, Vec::new())
+            }
+            JsonOperator::In | JsonOperator::NotIn | JsonOperator::Between => {
+                let Value::Array(values) = value else {
+                    return Err(invalid(format!(
+                        "`{}` on `{field}` takes an array of values",
+                        op.as_sql()
+                    )));
+                };
+
+                match (op, values.len()) {
+                    (JsonOperator::Between, 2) => {}
+                    (JsonOperator::Between, _) => {
+                        return Err(invalid(format!(
+                            "`BETWEEN` on `{field}` takes exactly two values"
+                        )));
+                    }
+                    (_, 0) => {
+                        return Err(invalid(format!(
+                            "`{}` on `{field}` takes at least one value",
+                            op.as_sql()
+                        )));
+                    }
+                    _ => {}
+                }
+
+                (op, values.into_iter().map(read).collect::<Result<_, _>>()?)
+            }
+            JsonOperator::Like | JsonOperator::ILike if kind != ValueKind::Text => {
+                return Err(invalid(format!(
+                    "`{}` is only supported on text fields, `{field}` is not text",
+                    op.as_sql()
+                )));
+            }
+            _ => (op, vec![read(value)?]),
+        };
+
+        Ok(Self { column, op, values })
+    }
+}
+
+/// Reads a single value for `field`, which must match `kind`.
+fn read_value(field: &str, kind: ValueKind, value: Value) -> crate::Result<CursorValue> {
+    let read = match (kind, &value) {
+        (ValueKind::Int, Value::Number(number)) => number.as_i64().map(CursorValue::Int),
+        (ValueKind::Float, Value::Number(number)) => number.as_f64().map(CursorValue::Float),
+        (ValueKind::Text, Value::String(text)) => Some(CursorValue::Text(text.clone())),
+        (ValueKind::Bool, Value::Bool(value)) => Some(CursorValue::Bool(*value)),
+        _ => None,
+    };
+
+    read.ok_or_else(|| {
+        invalid(format!(
+            "Expected a {kind:?} value for `{field}`, found `{value}`"
+        ))
+    })
+}
+
+impl<'args> SqlFilter<'args> for JsonCondition {
+    fn apply_filter(self, builder: &mut QueryBuilder<'args, Database>) {
+        builder.push(self.column);
+        builder.push(" ");
+        builder.push(self.op.as_sql());
+
+        match self.op {
+            JsonOperator::IsNull | JsonOperator::IsNotNull => {}
+            JsonOperator::In | JsonOperator::NotIn => {
+                builder.push(" (");
+
+                for (idx, value) in self.values.into_iter().enumerate() {
+                    if idx > 0 {
+                        builder.push(", ");
+                    }
+
+                    value.push_bind(builder);
+                }
+
+                builder.push(")");
+            }
+            _ => {
+                for (idx, value) in self.values.into_iter().enumerate() {
+                    builder.push(if idx > 0 { " AND " } else { " " });
+                    value.push_bind(builder);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn should_apply_filter(&self) -> bool {
+        true
+    }
+}
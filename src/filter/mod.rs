@@ -1,9 +1,20 @@
 mod_def! {
     pub mod operators;
+    pub mod boxed;
+    pub mod column;
+    pub mod render;
+    pub mod subquery;
 }
 
+#[cfg(feature = "serde")]
+mod json;
+
+#[cfg(feature = "serde")]
+pub use json::*;
+
 use crate::mod_def;
 use crate::traits::SqlFilter;
+use crate::types::Direction;
 use sqlx::QueryBuilder;
 
 pub struct Filter<T>(T);
@@ -13,6 +24,15 @@ impl<T> Filter<T> {
     pub fn new(filter: T) -> Self {
         Filter(filter)
     }
+
+    /// Erases the type of the filter, see [`BoxedFilter`].
+    #[inline]
+    pub fn boxed<'a>(self) -> BoxedFilter<'a>
+    where
+        T: for<'args> SqlFilter<'args> + Send + 'a,
+    {
+        BoxedFilter::new(self.0)
+    }
 }
 
 #[allow(clippy::should_implement_trait)]
@@ -86,4 +106,29 @@ where
     fn should_apply_filter(&self) -> bool {
         self.0.should_apply_filter()
     }
+
+    #[inline]
+    fn ordering(&self) -> Vec<(&'static str, Direction)> {
+        self.0.ordering()
+    }
+
+    #[inline]
+    fn apply_clauses(self, builder: &mut QueryBuilder<'args, crate::types::Database>) {
+        self.0.apply_clauses(builder);
+    }
+
+    #[inline]
+    fn groups_rows(&self) -> bool {
+        self.0.groups_rows()
+    }
+
+    #[inline]
+    fn row_limit(&self) -> Option<u64> {
+        self.0.row_limit()
+    }
+
+    #[inline]
+    fn row_offset(&self) -> Option<u64> {
+        self.0.row_offset()
+    }
 }
@@ -3,36 +3,69 @@ use crate::{sql_delimiter, sql_operator};
 use crate::traits::SqlFilter;
 use sqlx::QueryBuilder;
 
-/// UNSAFE AF!!!!
+/// Raw SQL written into the query as is, without any escaping.
+///
+/// Never build it from user input, it is only checked by whoever reviews the code. With the
+/// `strict-raw` feature it can only be created with [`Raw::unsafe_raw`], so every use can be
+/// found by searching for `unsafe_raw`.
+///
+/// Used as a filter the SQL is wrapped in parentheses, so an `OR` inside it can not bind to the
+/// filters it is combined with.
+#[cfg(not(feature = "strict-raw"))]
 pub struct Raw(pub &'static str);
 
+/// Raw SQL written into the query as is, without any escaping.
+///
+/// Never build it from user input, it is only checked by whoever reviews the code. With the
+/// `strict-raw` feature it can only be created with [`Raw::unsafe_raw`], so every use can be
+/// found by searching for `unsafe_raw`.
+///
+/// Used as a filter the SQL is wrapped in parentheses, so an `OR` inside it can not bind to the
+/// filters it is combined with.
+#[cfg(feature = "strict-raw")]
+pub struct Raw(pub(crate) &'static str);
+
+impl Raw {
+    /// Creates raw SQL which is written into the query as is.
+    #[inline]
+    pub const fn unsafe_raw(sql: &'static str) -> Self {
+        Self(sql)
+    }
+
+    /// Gets the raw SQL.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
 impl<'args> SqlFilter<'args> for Raw {
     #[inline]
     #[cfg(feature = "any")]
     fn apply_filter(self, builder: &mut QueryBuilder<'args, sqlx::Any>) {
         if self.should_apply_filter() {
-            builder.push(self.0);
+            builder.push("(").push(self.0).push(")");
         }
     }
     #[inline]
     #[cfg(all(feature = "postgres", not(any(feature = "sqlite", feature = "mysql", feature = "any"))))]
     fn apply_filter(self, builder: &mut QueryBuilder<'args, sqlx::Postgres>) {
         if self.should_apply_filter() {
-            builder.push(self.0);
+            builder.push("(").push(self.0).push(")");
         }
     }
     #[inline]
     #[cfg(all(feature = "mysql", not(any(feature = "sqlite", feature = "any", feature = "postgres"))))]
     fn apply_filter(self, builder: &mut QueryBuilder<'args, sqlx::MySql>) {
         if self.should_apply_filter() {
-            builder.push(self.0);
+            builder.push("(").push(self.0).push(")");
         }
     }
     #[inline]
     #[cfg(all(feature = "sqlite", not(any(feature = "any", feature = "mysql", feature = "postgres"))))]
     fn apply_filter(self, builder: &mut QueryBuilder<'args, sqlx::Sqlite>) {
         if self.should_apply_filter() {
-            builder.push(self.0);
+            builder.push("(").push(self.0).push(")");
         }
     }
 
@@ -44,6 +77,10 @@ impl<'args> SqlFilter<'args> for Raw {
 
 
 sql_delimiter! {
+    /// Matches rows matching both filters.
+    ///
+    /// When both sides apply they are wrapped in parentheses, so the grouping in SQL always
+    /// follows the order the filters were combined in.
     pub struct And<L, R> {
         pub left: L,
         pub right: R
@@ -55,9 +92,11 @@ sql_delimiter! {
             s.right.should_apply_filter(),
         ) {
             (true, true) => {
+                builder.push("(");
                 s.left.apply_filter(builder);
                 builder.push(" AND ");
                 s.right.apply_filter(builder);
+                builder.push(")");
             }
             (true, false) => {
                 s.left.apply_filter(builder);
@@ -72,9 +111,19 @@ sql_delimiter! {
     should_apply_filter(s) {
         s.left.should_apply_filter() || s.right.should_apply_filter()
     }
+
+    ordering(s) {
+        let mut ordering = s.left.ordering();
+        ordering.extend(s.right.ordering());
+        ordering
+    }
 }
 
 sql_delimiter! {
+    /// Matches rows matching either filter.
+    ///
+    /// When both sides apply they are wrapped in parentheses, so the grouping in SQL always
+    /// follows the order the filters were combined in.
     pub struct Or<L, R> {
         pub left: L,
         pub right: R
@@ -86,9 +135,11 @@ sql_delimiter! {
             s.right.should_apply_filter(),
         ) {
             (true, true) => {
+                builder.push("(");
                 s.left.apply_filter(builder);
                 builder.push(" OR ");
                 s.right.apply_filter(builder);
+                builder.push(")");
             }
             (true, false) => {
                 s.left.apply_filter(builder);
@@ -103,33 +154,51 @@ sql_delimiter! {
     should_apply_filter(s) {
         s.left.should_apply_filter() || s.right.should_apply_filter()
     }
+
+    ordering(s) {
+        let mut ordering = s.left.ordering();
+        ordering.extend(s.right.ordering());
+        ordering
+    }
 }
 
 sql_delimiter! {
+    /// Matches rows not matching the inner filter, which is wrapped in parentheses.
     pub struct Not<T> {
         pub inner: T,
     }
 
     apply_filter(s, builder) {
         if s.should_apply_filter() {
-            builder.push("NOT ");
+            builder.push("NOT (");
             s.inner.apply_filter(builder);
+            builder.push(")");
         }
     }
 
     should_apply_filter(s) {
         s.inner.should_apply_filter()
     }
+
+    ordering(s) {
+        s.inner.ordering()
+    }
 }
 
 sql_operator!(pub Equals, "=");
 sql_operator!(pub Like<String>, "LIKE");
-sql_operator!(pub InValues[], "IN");
 sql_operator!(pub NotEquals, "!=");
 sql_operator!(pub GreaterThan, ">");
 sql_operator!(pub LessThan, "<");
 sql_operator!(pub GreaterThanOrEqual, ">=");
 sql_operator!(pub LessThanOrEqual, "<=");
 sql_operator!(pub ILike<String>, "ILIKE");
-sql_operator!(pub NotInValues[], "NOT IN");
+// `in_values` skips an empty list, while `in_values_list` writes it as a condition matching no
+// rows for `IN` and every row for `NOT IN`, like the empty list would in SQL
+sql_operator!(pub InValues[], "IN", "1 = 0");
+sql_operator!(pub NotInValues[], "NOT IN", "1 = 1");
+// A value of `false` checks the opposite, so a single toggle can pick either side
+sql_operator!(pub IsNull(bool), "IS NULL", "IS NOT NULL");
+sql_operator!(pub IsNotNull(bool), "IS NOT NULL", "IS NULL");
+sql_operator!(pub Between(low, high), "BETWEEN", "AND");
 sql_operator!(pub NoOpFilter);
\ No newline at end of file
@@ -0,0 +1,140 @@
+use crate::traits::SqlFilter;
+use crate::types::{Database, QueryBuilder};
+use crate::utils::take_query;
+use sqlx::{Arguments, Database as DatabaseTrait, Encode, Type};
+use std::cell::RefCell;
+use std::fmt::{Debug, Display, Formatter};
+
+thread_local! {
+    /// The values bound with [`push_bind`] while arguments are captured, see [`capture_arguments`].
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Binds `value` like [`QueryBuilder::push_bind`] and keeps its [`Debug`] output for
+/// [`RenderedFilter`].
+///
+/// Every filter of this crate binds its values through this function. Custom filters calling
+/// [`QueryBuilder::push_bind`] directly still work, but their values are rendered as `<value>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::filter::push_bind;
+/// # use sqlx_utils::traits::SqlFilter;
+/// # use sqlx_utils::types::{Database, QueryBuilder};
+/// struct Adult;
+///
+/// impl<'args> SqlFilter<'args> for Adult {
+///     fn apply_filter(self, builder: &mut QueryBuilder<'args, Database>) {
+///         builder.push("age >= ");
+///         push_bind(builder, 18);
+///     }
+///
+///     fn should_apply_filter(&self) -> bool {
+///         true
+///     }
+/// }
+///
+/// assert_eq!(Adult.render().to_string(), "age >= ? [18]");
+/// ```
+pub fn push_bind<'args, T>(builder: &mut QueryBuilder<'args, Database>, value: T)
+where
+    T: Type<Database> + Encode<'args, Database> + Debug + 'args,
+{
+    CAPTURED.with_borrow_mut(|captured| {
+        if let Some(captured) = captured {
+            captured.push(format!("{value:?}"));
+        }
+    });
+
+    builder.push_bind(value);
+}
+
+/// Runs `f`, returning the [`Debug`] output of every value bound with [`push_bind`] meanwhile.
+///
+/// Captures can be nested, the values bound in an inner capture are not part of the outer one.
+pub(crate) fn capture_arguments<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    /// Restores the outer capture, even if `f` panics.
+    struct Restore(Option<Vec<String>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CAPTURED.set(self.0.take());
+        }
+    }
+
+    let outer = Restore(CAPTURED.replace(Some(Vec::new())));
+    let result = f();
+    let captured = CAPTURED.replace(None).unwrap_or_default();
+    drop(outer);
+
+    (result, captured)
+}
+
+/// The SQL of a filter or query next to the values bound to it, for logging and tests.
+///
+/// The SQL uses the placeholders of the database, `$1` on Postgres and `?` everywhere else.
+/// The bound values are shown with their [`Debug`] output as they were passed to [`push_bind`],
+/// the same on every database.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::filter::{equals, like};
+/// # use sqlx_utils::traits::SqlFilter;
+/// let rendered = equals("id", Some(1)).and(like("name", Some("A%"))).render();
+///
+/// assert_eq!(rendered.sql, "(id = ? AND name LIKE ?)");
+/// assert_eq!(rendered.arguments, ["1", "\"A%\""]);
+/// assert_eq!(rendered.to_string(), "(id = ? AND name LIKE ?) [1, \"A%\"]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RenderedFilter {
+    /// The SQL, with placeholders for the bound values.
+    pub sql: String,
+    /// The bound values in the order of their placeholders, formatted for display.
+    pub arguments: Vec<String>,
+}
+
+impl RenderedFilter {
+    /// Renders `filter` on its own, without checking if it should be applied.
+    pub fn new<'args>(filter: impl SqlFilter<'args>) -> Self {
+        let mut builder = QueryBuilder::new("");
+        let ((), captured) = capture_arguments(|| filter.apply_filter(&mut builder));
+
+        match take_query(&mut builder, &captured) {
+            Ok((sql, arguments)) => Self::from_arguments(sql, &arguments, &captured),
+            Err(error) => Self {
+                sql: builder.into_sql(),
+                arguments: vec![format!("<error: {error}>")],
+            },
+        }
+    }
+
+    /// Renders a query with its `arguments`, shown as the `captured` values bound to them.
+    ///
+    /// If some values were not bound through [`push_bind`] the captured values can not be matched
+    /// to their placeholders, so every value is shown as `<value>` instead.
+    pub(crate) fn from_arguments(
+        sql: &str,
+        arguments: &<Database as DatabaseTrait>::Arguments<'_>,
+        captured: &[String],
+    ) -> Self {
+        let arguments = if captured.len() == arguments.len() {
+            captured.to_vec()
+        } else {
+            vec!["<value>".to_string(); arguments.len()]
+        };
+
+        Self {
+            sql: sql.to_string(),
+            arguments,
+        }
+    }
+}
+
+impl Display for RenderedFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]", self.sql, self.arguments.join(", "))
+    }
+}
@@ -0,0 +1,127 @@
+use crate::filter::{Column, Filter};
+use crate::sql_delimiter;
+use crate::traits::{SelectFilter, SqlFilter};
+use crate::types::Database;
+use crate::utils::push_clauses;
+use sqlx::QueryBuilder;
+
+/// A query nested in a filter, made of the start of the query up to the `WHERE` clause and a
+/// filter for the rest.
+///
+/// Every filter made with [`sql_filter!`](crate::sql_filter) already knows its query, so it can
+/// be passed as is wherever a subquery is expected. Other filters are given their query with
+/// [`Subquery::new`].
+///
+/// The clauses of the filter are added like when it is used with a repository, including its
+/// `GROUP BY`, `LIMIT` and `OFFSET`. Its arguments are bound in the same query as the outer
+/// filter, so the placeholders stay numbered in order on Postgres.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::sql_filter;
+/// # use sqlx_utils::filter::{Subquery, equals, exists, in_subquery};
+/// sql_filter! {
+///     pub struct CustomerOrderFilter {
+///         SELECT customer_id FROM orders WHERE
+///         ?total >= f64
+///     }
+/// }
+///
+/// // id IN (SELECT customer_id FROM orders WHERE total >= ?)
+/// let filter = in_subquery("id", CustomerOrderFilter::new().total(100.0));
+///
+/// // EXISTS (SELECT 1 FROM orders WHERE state = ?)
+/// let filter = exists(Subquery::new("SELECT 1 FROM orders", equals("state", Some("open"))));
+/// ```
+pub struct Subquery<F> {
+    query: &'static str,
+    filter: F,
+}
+
+impl<F> Subquery<F> {
+    /// Creates a subquery of `query` filtered by `filter`, where `query` is the start of the
+    /// query up to the `WHERE` clause.
+    #[inline]
+    pub fn new(query: &'static str, filter: F) -> Self {
+        Self { query, filter }
+    }
+}
+
+impl<F: SelectFilter> From<F> for Subquery<F> {
+    #[inline]
+    fn from(filter: F) -> Self {
+        Self::new(F::QUERY, filter)
+    }
+}
+
+impl<'args, F: SqlFilter<'args>> Subquery<F> {
+    /// Pushes the subquery wrapped in parentheses.
+    fn push(self, builder: &mut QueryBuilder<'args, Database>) {
+        builder.push("(");
+        builder.push(self.query);
+
+        let ordered = self.filter.row_limit().is_some() || self.filter.row_offset().is_some();
+        push_clauses(builder, self.filter, ordered);
+
+        builder.push(")");
+    }
+}
+
+sql_delimiter! {
+    /// Matches rows where the column is one of the values returned by the subquery.
+    pub struct InSubquery<F> {
+        pub column: Column,
+        pub subquery: Subquery<F>
+    }
+
+    apply_filter(s, builder) {
+        s.column.push(builder);
+        builder.push(" IN ");
+        s.subquery.push(builder);
+    }
+
+    should_apply_filter(_s) {
+        true
+    }
+}
+
+sql_delimiter! {
+    /// Matches rows for which the subquery returns any rows.
+    ///
+    /// Use [`not`](Filter::not) for `NOT EXISTS`.
+    pub struct Exists<F> {
+        pub subquery: Subquery<F>
+    }
+
+    apply_filter(s, builder) {
+        builder.push("EXISTS ");
+        s.subquery.push(builder);
+    }
+
+    should_apply_filter(_s) {
+        true
+    }
+}
+
+/// Matches rows where `column` is one of the values returned by `subquery`, like
+/// `id IN (SELECT customer_id FROM orders WHERE ...)`.
+///
+/// The filter always applies, even when the filter of the subquery does not.
+#[inline]
+pub fn in_subquery<F>(
+    column: impl Into<Column>,
+    subquery: impl Into<Subquery<F>>,
+) -> Filter<InSubquery<F>> {
+    Filter::new(InSubquery::new(column.into(), subquery.into()))
+}
+
+/// Matches rows for which `subquery` returns any rows, like `EXISTS (SELECT 1 FROM ...)`.
+///
+/// A subquery referring to the outer query, like `o.customer_id = c.id`, can do so with a
+/// [`Raw`](crate::filter::Raw) condition. The filter always applies, even when the filter of the
+/// subquery does not.
+#[inline]
+pub fn exists<F>(subquery: impl Into<Subquery<F>>) -> Filter<Exists<F>> {
+    Filter::new(Exists::new(subquery.into()))
+}
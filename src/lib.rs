@@ -14,3 +14,7 @@ pub mod utils;
 
 pub use error::{Error, Result};
 pub use sqlx_utils_macro::sql_filter;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
@@ -16,6 +16,7 @@ macro_rules! sql_delimiter {
 
         $apply_filter:ident ($apply_self:ident, $builder:ident) $apply_block:block
         $should_apply_filter:ident($should_self:ident) $should_apply_block:block
+        $(ordering($ordering_self:ident) $ordering_block:block)?
         $(where $($where_clause:tt)+)?
     } => {
         $(#[$struct_meta])*
@@ -39,6 +40,7 @@ macro_rules! sql_delimiter {
             $ident$(<$($lt,)* $($generic),*>)?;
             $apply_filter($apply_self, $builder) $apply_block
             $should_apply_filter($should_self) $should_apply_block
+            $(ordering($ordering_self) $ordering_block)?
             $(where
                 $($generic: $crate::traits::SqlFilter<'args>),*)?
         }
@@ -10,6 +10,7 @@ macro_rules! sql_impl {
         >)?;
         $apply_filter:ident ($apply_self:ident, $builder:ident) $apply_block:block
         $should_apply_filter:ident($should_self:ident) $should_apply_block:block
+        $(ordering($ordering_self:ident) $ordering_block:block)?
         $(where $($where_clause:tt)+)?
     } => {
         impl<'args $(, $($lt,)* $($generic),*)?> $crate::traits::SqlFilter<'args>
@@ -30,6 +31,14 @@ macro_rules! sql_impl {
                 let should_apply_impl = |$should_self: &Self| $should_apply_block;
                 should_apply_impl(self)
             }
+
+            $(
+                #[inline]
+                fn ordering(&self) -> ::std::vec::Vec<(&'static str, $crate::types::Direction)> {
+                    let ordering_impl = |$ordering_self: &Self| $ordering_block;
+                    ordering_impl(self)
+                }
+            )?
         }
     };
 
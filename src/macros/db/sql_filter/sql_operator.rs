@@ -3,7 +3,7 @@
 macro_rules! sql_operator {
     ($vis:vis $ident:ident, $lit:literal) => {
         $vis struct $ident<T> {
-            column: &'static str,
+            column: $crate::filter::Column,
             value: Option<T>,
         }
 
@@ -12,15 +12,15 @@ macro_rules! sql_operator {
             T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
         {
             #[inline]
-            $vis fn new(column: &'static str, value: Option<T>) -> Self {
-                Self { column, value }
+            $vis fn new(column: impl Into<$crate::filter::Column>, value: Option<T>) -> Self {
+                Self { column: column.into(), value }
             }
         }
 
         impl $ident<$crate::filter::Raw> {
             #[inline]
-            $vis fn new_raw(column: &'static str, value: $crate::filter::Raw) -> Self {
-                Self { column, value: Some(value) }
+            $vis fn new_raw(column: impl Into<$crate::filter::Column>, value: $crate::filter::Raw) -> Self {
+                Self { column: column.into(), value: Some(value) }
             }
         }
 
@@ -29,9 +29,9 @@ macro_rules! sql_operator {
 
             apply_filter(s, builder) {
                 if let Some(val) = s.value {
-                    builder.push(s.column);
+                    s.column.push(builder);
                     builder.push(concat!(" ", $lit, " "));
-                    builder.push_bind(val);
+                    $crate::filter::push_bind(builder, val);
                 }
             }
 
@@ -40,7 +40,7 @@ macro_rules! sql_operator {
             }
 
             where
-                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database>
+                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + ::core::fmt::Debug
         }
 
         impl<'args> $crate::traits::SqlFilter<'args> for $ident<$crate::filter::Raw>
@@ -48,9 +48,9 @@ macro_rules! sql_operator {
             #[inline]
             fn apply_filter(self, builder: &mut ::sqlx::QueryBuilder<'args, $crate::types::Database>) {
                 if let Some(val) = self.value {
-                    builder.push(self.column);
+                    self.column.push(builder);
                     builder.push(concat!(" ", $lit, " "));
-                    val.apply_filter(builder)
+                    builder.push(val.as_str());
                 }
 
             }
@@ -64,7 +64,7 @@ macro_rules! sql_operator {
         ::paste::paste! {
             #[inline]
             pub fn [< $ident:snake >]<'args, T>(
-                column: &'static str,
+                column: impl Into<$crate::filter::Column>,
                 value: Option<T>
             ) -> $crate::filter::Filter<$ident<T>>
             where
@@ -75,7 +75,7 @@ macro_rules! sql_operator {
 
             #[inline]
             pub fn [< $ident:snake _raw >](
-                column: &'static str,
+                column: impl Into<$crate::filter::Column>,
                 value: $crate::filter::Raw
             ) -> $crate::filter::Filter<$ident<$crate::filter::Raw>> {
                 $crate::filter::Filter::new($ident::new_raw(column, value))
@@ -85,15 +85,15 @@ macro_rules! sql_operator {
 
     ($vis:vis $ident:ident<$ty:ty>, $lit:literal) => {
         $vis struct $ident {
-            column: &'static str,
+            column: $crate::filter::Column,
             value: Option<$ty>,
         }
 
         impl $ident {
             #[inline]
-            $vis fn new(column: &'static str, value: Option<impl Into<$ty>>) -> Self {
+            $vis fn new(column: impl Into<$crate::filter::Column>, value: Option<impl Into<$ty>>) -> Self {
                 let value = value.map(::core::convert::Into::into);
-                Self { column, value }
+                Self { column: column.into(), value }
             }
         }
 
@@ -102,9 +102,9 @@ macro_rules! sql_operator {
 
             apply_filter(s, builder) {
                 if let Some(value) = s.value {
-                    builder.push(s.column);
+                    s.column.push(builder);
                     builder.push(concat!(" ", $lit, " "));
-                    builder.push_bind(value);
+                    $crate::filter::push_bind(builder, value);
                 }
             }
 
@@ -116,7 +116,7 @@ macro_rules! sql_operator {
         ::paste::paste! {
             #[inline]
             pub fn [< $ident:snake >](
-                column: &'static str,
+                column: impl Into<$crate::filter::Column>,
                 value: Option<impl Into<$ty>>
             ) -> $crate::filter::Filter<$ident> {
                 $crate::filter::Filter::new($ident::new(column, value))
@@ -124,28 +124,144 @@ macro_rules! sql_operator {
 
             #[inline]
             pub fn [< $ident:snake _raw >](
-                column: &'static str,
+                column: impl Into<$crate::filter::Column>,
                 value: $crate::filter::Raw
             ) -> $crate::filter::Filter<$ident> {
-                $crate::filter::Filter::new($ident::new(column, Some(value.0)))
+                $crate::filter::Filter::new($ident::new(column, Some(value.as_str())))
             }
         }
     };
 
-    ($vis:vis $ident:ident[], $lit:literal) => {
+    ($vis:vis $ident:ident(bool), $lit:literal, $inverse:literal) => {
+        $vis struct $ident {
+            column: $crate::filter::Column,
+            value: Option<bool>,
+        }
+
+        impl $ident {
+            #[inline]
+            $vis fn new(column: impl Into<$crate::filter::Column>, value: Option<bool>) -> Self {
+                Self { column: column.into(), value }
+            }
+        }
+
+        $crate::sql_impl! {
+            $ident;
+
+            apply_filter(s, builder) {
+                if let Some(value) = s.value {
+                    s.column.push(builder);
+                    builder.push(if value {
+                        concat!(" ", $lit)
+                    } else {
+                        concat!(" ", $inverse)
+                    });
+                }
+            }
+
+            should_apply_filter(s) {
+                s.value.is_some()
+            }
+        }
+
+        ::paste::paste! {
+            #[inline]
+            pub fn [< $ident:snake >](
+                column: impl Into<$crate::filter::Column>,
+                value: Option<bool>
+            ) -> $crate::filter::Filter<$ident> {
+                $crate::filter::Filter::new($ident::new(column, value))
+            }
+        }
+    };
+
+    ($vis:vis $ident:ident(low, high), $lit:literal, $separator:literal) => {
+        $vis struct $ident<T, U> {
+            column: $crate::filter::Column,
+            value: Option<(T, U)>,
+        }
+
+        impl<'args, T, U> $ident<T, U>
+        where
+            T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
+            U: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
+        {
+            #[inline]
+            $vis fn new(column: impl Into<$crate::filter::Column>, value: Option<(T, U)>) -> Self {
+                Self { column: column.into(), value }
+            }
+        }
+
+        $crate::sql_impl! {
+            $ident<T, U>;
+
+            apply_filter(s, builder) {
+                if let Some((low, high)) = s.value {
+                    s.column.push(builder);
+                    builder.push(concat!(" ", $lit, " "));
+                    $crate::filter::push_bind(builder, low);
+                    builder.push(concat!(" ", $separator, " "));
+                    $crate::filter::push_bind(builder, high);
+                }
+            }
+
+            should_apply_filter(s) {
+                s.value.is_some()
+            }
+
+            where
+                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + ::core::fmt::Debug,
+                U: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + ::core::fmt::Debug
+        }
+
+        ::paste::paste! {
+            #[inline]
+            pub fn [< $ident:snake >]<'args, T, U>(
+                column: impl Into<$crate::filter::Column>,
+                value: Option<(T, U)>
+            ) -> $crate::filter::Filter<$ident<T, U>>
+            where
+                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
+                U: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
+            {
+                $crate::filter::Filter::new($ident::new(column, value))
+            }
+        }
+    };
+
+    ($vis:vis $ident:ident[], $lit:literal, $empty:literal) => {
         $vis struct $ident<T> {
-            column: &'static str,
-            values: Vec<T>,
+            column: $crate::filter::Column,
+            values: Option<Vec<T>>,
         }
 
         impl<'args, T> $ident<T>
         where
             T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
         {
+            /// Creates the condition, which is skipped if `values` is empty.
             #[inline]
-            $vis fn new(column: &'static str, values: impl IntoIterator<Item = T>) -> Self {
-                let values = values.into_iter().collect();
-                Self { column, values }
+            $vis fn new(column: impl Into<$crate::filter::Column>, values: impl IntoIterator<Item = T>) -> Self {
+                let values = values.into_iter().collect::<Vec<_>>();
+                let values = (!values.is_empty()).then_some(values);
+
+                Self { column: column.into(), values }
+            }
+
+            /// Creates the condition, which is skipped if `values` is [`None`] while an empty list
+            #[doc = concat!("is written as `", $empty, "`.")]
+            #[inline]
+            $vis fn new_list(column: impl Into<$crate::filter::Column>, values: Option<impl IntoIterator<Item = T>>) -> Self {
+                let values = values.map(|values| values.into_iter().collect());
+
+                Self { column: column.into(), values }
+            }
+        }
+
+        impl $ident<$crate::filter::Raw> {
+            #[inline]
+            $vis fn new_raw(column: impl Into<$crate::filter::Column>, value: $crate::filter::Raw) -> Self {
+                Self { column: column.into(), values: Some(vec![value]) }
             }
         }
 
@@ -153,19 +269,24 @@ macro_rules! sql_operator {
             $ident<T>;
 
             apply_filter(s, builder) {
-                if !s.should_apply_filter()  {
+                let Some(values) = s.values else {
+                    return;
+                };
+
+                if values.is_empty() {
+                    builder.push($empty);
                     return;
                 }
 
-                builder.push(s.column);
+                s.column.push(builder);
                 builder.push(concat!(" ", $lit, " ("));
 
                 let mut first = true;
-                for val in s.values {
+                for val in values {
                     if !first {
                         builder.push(", ");
                     }
-                    builder.push_bind(val);
+                    $crate::filter::push_bind(builder, val);
                     first = false;
                 }
 
@@ -173,17 +294,33 @@ macro_rules! sql_operator {
             }
 
             should_apply_filter(s) {
-                !s.values.is_empty()
+                s.values.is_some()
             }
 
             where
-                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database>
+                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + ::core::fmt::Debug
+        }
+
+        impl<'args> $crate::traits::SqlFilter<'args> for $ident<$crate::filter::Raw> {
+            #[inline]
+            fn apply_filter(self, builder: &mut ::sqlx::QueryBuilder<'args, $crate::types::Database>) {
+                for val in self.values.into_iter().flatten() {
+                    self.column.push(builder);
+                    builder.push(concat!(" ", $lit, " "));
+                    builder.push(val.as_str());
+                }
+            }
+
+            #[inline]
+            fn should_apply_filter(&self) -> bool {
+                self.values.is_some()
+            }
         }
 
         ::paste::paste! {
             #[inline]
             pub fn [< $ident:snake >]<'args, T>(
-                column: &'static str,
+                column: impl Into<$crate::filter::Column>,
                 values: impl IntoIterator<Item = T>
             ) -> $crate::filter::Filter<$ident<T>>
             where
@@ -191,6 +328,25 @@ macro_rules! sql_operator {
             {
                 $crate::filter::Filter::new($ident::new(column, values))
             }
+
+            #[inline]
+            pub fn [< $ident:snake _list >]<'args, T>(
+                column: impl Into<$crate::filter::Column>,
+                values: Option<impl IntoIterator<Item = T>>
+            ) -> $crate::filter::Filter<$ident<T>>
+            where
+                T: ::sqlx::Type<$crate::types::Database> + ::sqlx::Encode<'args, $crate::types::Database> + 'args,
+            {
+                $crate::filter::Filter::new($ident::new_list(column, values))
+            }
+
+            #[inline]
+            pub fn [< $ident:snake _raw >](
+                column: impl Into<$crate::filter::Column>,
+                value: $crate::filter::Raw
+            ) -> $crate::filter::Filter<$ident<$crate::filter::Raw>> {
+                $crate::filter::Filter::new($ident::new_raw(column, value))
+            }
         }
     };
 
@@ -65,8 +65,66 @@ mod repository;
 ///     PersonRepository<Person>; // The generated type `PersonRepository` will now have size of 0
 /// }
 /// ```
+///
+/// # CRUD Repository
+///
+/// If the model implements [`Table`](crate::traits::Table), usually through
+/// `#[derive(Model)]` with `#[model(table = "...")]`, the `!crud` flag implements
+/// [`InsertableRepository`](crate::traits::InsertableRepository),
+/// [`UpdatableRepository`](crate::traits::UpdatableRepository),
+/// [`DeleteRepository`](crate::traits::DeleteRepository) and
+/// [`SelectRepository`](crate::traits::SelectRepository) using the queries of the model.
+/// It can be combined with `!zst` as `!zst !crud`.
+///
+/// # Example of a CRUD Repository
+///
+/// ```
+/// # use sqlx_utils::prelude::*;
+/// #[derive(Model, sqlx::FromRow)]
+/// #[model(table = "persons")]
+/// struct Person {
+///     id: String,
+///     name: String
+/// }
+///
+/// repository!{
+///     !crud
+///     PersonRepository<Person>;
+/// }
+/// ```
 #[macro_export]
 macro_rules! repository {
+    {
+        !crud
+        $( #[$meta:meta] )*
+        $vis:vis $ident:ident<$model:ty>;
+
+        $($tokens:tt)*
+    } => {
+        $crate::repository!($(#[$meta])* $vis $ident<$model>; $($tokens)*);
+        $crate::repository!(!crud_impl $ident<$model>);
+    };
+
+    {
+        !zst !crud
+        $( #[$meta:meta] )*
+        $vis:vis $ident:ident<$model:ty>;
+
+        $($tokens:tt)*
+    } => {
+        $crate::repository!(!zst $(#[$meta])* $vis $ident<$model>; $($tokens)*);
+        $crate::repository!(!crud_impl $ident<$model>);
+    };
+
+    {
+        !crud_impl $ident:ident<$model:ty>
+    } => {
+        $crate::repository_insert!($ident<$model>;);
+        $crate::repository_update!($ident<$model>;);
+        $crate::repository_delete!($ident<$model>;);
+        $crate::repository_select!($ident<$model>;);
+    };
+
     {
         $( #[$meta:meta] )*
         $vis:vis $ident:ident;
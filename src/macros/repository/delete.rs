@@ -6,6 +6,23 @@ macro_rules! repository_delete {
         $crate::repository_delete!(
             !inner
             $ident<$model>;
+
+            #[inline]
+            fn delete_by_id_query(id: &<$model as $crate::traits::Model>::Id) -> $crate::types::Query<'_> {
+                <$model as $crate::traits::Table>::delete_by_id_query(id)
+            }
+
+            #[inline]
+            fn delete_by_filter_query<'args>(filter: impl $crate::prelude::SqlFilter<'args>) -> $crate::types::QueryBuilder<'args, $crate::prelude::Database> {
+                let mut builder = $crate::types::QueryBuilder::new(::std::format!(
+                    "DELETE FROM {} WHERE ",
+                    <$model as $crate::traits::Table>::TABLE_NAME
+                ));
+
+                filter.apply_filter(&mut builder);
+
+                builder
+            }
         );
     };
 
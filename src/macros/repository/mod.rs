@@ -1,5 +1,7 @@
 mod delete;
+mod select;
 mod update;
+mod upsert;
 
 #[macro_export]
 macro_rules! repository_insert {
@@ -9,6 +11,11 @@ macro_rules! repository_insert {
         $crate::repository_insert!(
             !inner
             $ident<$model>;
+
+            #[inline]
+            fn insert_query(model: &$model) -> $crate::types::Query<'_> {
+                <$model as $crate::traits::Table>::insert_query(model)
+            }
         );
     };
 
@@ -0,0 +1,43 @@
+#[macro_export]
+macro_rules! repository_select {
+    {
+        $ident:ident<$model:ty>;
+    } => {
+        $crate::repository_select!(
+            !inner
+            $ident<$model>;
+
+            #[inline]
+            fn get_all_query(&self) -> $crate::types::QueryAs<'_, $model> {
+                <$model as $crate::traits::Table>::get_all_query()
+            }
+
+            #[inline]
+            fn get_by_id_query(
+                &self,
+                id: impl Into<<$model as $crate::traits::Model>::Id>,
+            ) -> $crate::types::QueryAs<'_, $model> {
+                <$model as $crate::traits::Table>::get_by_id_query(id.into())
+            }
+        );
+    };
+
+    {
+        $ident:ident<$model:ty>;
+
+        $($tokens:tt)*
+    } => {
+        $crate::repository_select!(!inner $ident<$model>; $($tokens)*);
+    };
+
+    {
+        !inner
+        $ident:ident<$model:ty>;
+
+        $($tokens:tt)*
+    } => {
+        impl $crate::traits::SelectRepository<$model> for $ident {
+            $($tokens)*
+        }
+    }
+}
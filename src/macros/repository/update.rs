@@ -6,6 +6,11 @@ macro_rules! repository_update {
         $crate::repository_update!(
             !inner
             $ident<$model>;
+
+            #[inline]
+            fn update_query(model: &$model) -> $crate::types::Query<'_> {
+                <$model as $crate::traits::Table>::update_query(model)
+            }
         );
     };
 
@@ -0,0 +1,27 @@
+#[macro_export]
+macro_rules! repository_upsert {
+    {
+        $ident:ident<$model:ty>;
+    } => {
+        $crate::repository_upsert!(!inner $ident<$model>;);
+    };
+
+    {
+        $ident:ident<$model:ty>;
+
+        $($tokens:tt)*
+    } => {
+        $crate::repository_upsert!(!inner $ident<$model>; $($tokens)*);
+    };
+
+    {
+        !inner
+        $ident:ident<$model:ty>;
+
+        $($tokens:tt)*
+    } => {
+        impl $crate::traits::UpsertRepository<$model> for $ident {
+            $($tokens)*
+        }
+    }
+}
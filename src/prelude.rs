@@ -1,8 +1,11 @@
-pub use crate::error::Error;
+pub use crate::error::{Error, RetryableError};
 pub use crate::pool::*;
 pub use crate::sql_filter;
 pub use crate::traits::*;
 pub use crate::types::*;
-pub use crate::{repository, repository_delete, repository_insert, repository_update};
+pub use crate::{
+    repository, repository_delete, repository_insert, repository_select, repository_update,
+    repository_upsert,
+};
 
 pub use crate::sqlx::*;
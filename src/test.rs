@@ -35,3 +35,711 @@ fn test_macro() {
     t.pass("tests/ui/pass/*.rs");
     t.compile_fail("tests/ui/fail-try-parse-nightly/*.rs");
 }
+
+#[test]
+fn test_dialect_from_scheme() {
+    use crate::types::Dialect;
+
+    assert_eq!(Dialect::from_scheme("postgres"), Some(Dialect::Postgres));
+    assert_eq!(Dialect::from_scheme("postgresql"), Some(Dialect::Postgres));
+    assert_eq!(Dialect::from_scheme("mysql"), Some(Dialect::MySql));
+    assert_eq!(Dialect::from_scheme("mariadb"), Some(Dialect::MySql));
+    assert_eq!(Dialect::from_scheme("sqlite"), Some(Dialect::Sqlite));
+    assert_eq!(Dialect::from_scheme("mssql"), None);
+
+    assert!(Dialect::Postgres.supports_returning());
+    assert!(Dialect::Sqlite.supports_returning());
+    assert!(!Dialect::MySql.supports_returning());
+
+    assert_eq!(Dialect::Sqlite.max_rows(2), 16383);
+    assert_eq!(Dialect::Postgres.max_rows(10), 6553);
+    assert_eq!(Dialect::MySql.max_rows(0), 65535);
+    assert_eq!(Dialect::Sqlite.max_rows(40000), 1);
+    assert_eq!(Dialect::Sqlite.checked_max_rows(2).unwrap(), 16383);
+    assert_eq!(Dialect::Sqlite.checked_max_rows(32766).unwrap(), 1);
+    assert!(Dialect::Sqlite.checked_max_rows(32767).is_err());
+    assert!(Dialect::Postgres.checked_max_rows(40000).is_ok());
+}
+
+#[test]
+fn test_page_request_sql() {
+    use crate::types::{Cursor, Direction, PageRequest, QueryBuilder};
+
+    let render = |request: PageRequest| {
+        let mut builder = QueryBuilder::new("SELECT * FROM (SELECT * FROM users) AS page");
+        request.push_page(&mut builder).map(|_| builder.into_sql())
+    };
+
+    assert_eq!(
+        render(PageRequest::offset(20, 40)).unwrap(),
+        "SELECT * FROM (SELECT * FROM users) AS page LIMIT 21 OFFSET 40"
+    );
+    assert_eq!(
+        render(PageRequest::offset(10, 0).order_by(&["name", "id"], Direction::Desc)).unwrap(),
+        "SELECT * FROM (SELECT * FROM users) AS page ORDER BY name DESC, id DESC LIMIT 11 OFFSET 0"
+    );
+    assert_eq!(
+        render(PageRequest::keyset(10, &["id"])).unwrap(),
+        "SELECT * FROM (SELECT * FROM users) AS page ORDER BY id ASC LIMIT 11"
+    );
+    assert_eq!(
+        render(
+            PageRequest::keyset(10, &["name", "id"])
+                .direction(Direction::Desc)
+                .after(Cursor(vec!["Alice".into(), 5i64.into()]))
+        )
+        .unwrap(),
+        "SELECT * FROM (SELECT * FROM users) AS page WHERE (name, id) < (?, ?) \
+         ORDER BY name DESC, id DESC LIMIT 11"
+    );
+
+    assert!(render(PageRequest::keyset(10, &[])).is_err());
+    assert!(render(PageRequest::keyset(10, &["id"]).after(Cursor(vec![]))).is_err());
+}
+
+#[test]
+fn test_transaction_options_sql() {
+    use crate::types::{BeginMode, Dialect, IsolationLevel, TransactionOptions};
+
+    let default = TransactionOptions::new();
+
+    assert_eq!(default.begin_sql(Dialect::Postgres), None);
+    assert_eq!(default.begin_sql(Dialect::MySql), None);
+    assert_eq!(default.begin_sql(Dialect::Sqlite), None);
+
+    let report = TransactionOptions::new()
+        .isolation_level(IsolationLevel::Serializable)
+        .read_only(true)
+        .deferrable(true);
+
+    assert_eq!(
+        report.begin_sql(Dialect::Postgres).as_deref(),
+        Some("BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE")
+    );
+    assert_eq!(
+        report.begin_sql(Dialect::MySql).as_deref(),
+        Some("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; START TRANSACTION READ ONLY")
+    );
+    assert_eq!(report.begin_sql(Dialect::Sqlite), None);
+
+    let read_only = TransactionOptions::new().read_only(true);
+
+    assert_eq!(
+        read_only.begin_sql(Dialect::MySql).as_deref(),
+        Some("START TRANSACTION READ ONLY")
+    );
+
+    let committed = TransactionOptions::new().isolation_level(IsolationLevel::ReadCommitted);
+
+    assert_eq!(
+        committed.begin_sql(Dialect::MySql).as_deref(),
+        Some("SET TRANSACTION ISOLATION LEVEL READ COMMITTED; START TRANSACTION")
+    );
+
+    let writer = TransactionOptions::new().begin_mode(BeginMode::Exclusive);
+
+    assert_eq!(
+        writer.begin_sql(Dialect::Sqlite).as_deref(),
+        Some("BEGIN EXCLUSIVE")
+    );
+    assert_eq!(writer.begin_sql(Dialect::Postgres), None);
+    assert_eq!(writer.begin_sql(Dialect::MySql), None);
+}
+
+#[test]
+fn test_retry_policy_delay() {
+    use crate::types::RetryPolicy;
+    use std::time::Duration;
+
+    let policy = RetryPolicy::new(10)
+        .base_delay(Duration::from_millis(10))
+        .max_delay(Duration::from_millis(100))
+        .jitter(false);
+
+    assert_eq!(policy.delay(1), Duration::from_millis(10));
+    assert_eq!(policy.delay(2), Duration::from_millis(20));
+    assert_eq!(policy.delay(4), Duration::from_millis(80));
+    assert_eq!(policy.delay(5), Duration::from_millis(100));
+    assert_eq!(policy.delay(u32::MAX), Duration::from_millis(100));
+
+    let policy = policy.jitter(true);
+
+    for attempt in 1..10 {
+        let delay = policy.delay(attempt);
+        let backoff = policy.jitter(false).delay(attempt);
+
+        assert!(delay >= backoff / 2 && delay <= backoff);
+    }
+}
+
+#[test]
+fn test_retryable_error() {
+    use crate::error::RetryableError;
+    use sqlx::error::{DatabaseError, ErrorKind};
+    use std::borrow::Cow;
+
+    #[derive(Debug)]
+    struct CodeError(&'static str);
+
+    impl std::fmt::Display for CodeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl std::error::Error for CodeError {}
+
+    impl DatabaseError for CodeError {
+        fn message(&self) -> &str {
+            self.0
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(self.0.into())
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    let error = |code| sqlx::Error::Database(Box::new(CodeError(code)));
+
+    for code in ["40001", "40P01", "5", "517"] {
+        assert!(error(code).is_retryable(), "{code} should be retryable");
+        assert!(crate::Error::from(error(code)).is_retryable());
+    }
+
+    for code in ["23505", "42P01", "1", "6"] {
+        assert!(
+            !error(code).is_retryable(),
+            "{code} should not be retryable"
+        );
+    }
+
+    assert!(!sqlx::Error::RowNotFound.is_retryable());
+    assert!(!crate::Error::MutexLockError.is_retryable());
+}
+
+#[tokio::test]
+async fn test_producer_stream() {
+    use crate::utils::{forward_stream, producer_stream};
+    use futures::{StreamExt, stream};
+
+    let rows = vec![Ok(1), Ok(2), Err(sqlx::Error::RowNotFound), Ok(3)];
+
+    let items = producer_stream(tracing::Span::none(), move |sender| {
+        forward_stream(stream::iter(rows), sender)
+    })
+    .collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(items.len(), 4);
+    assert!(matches!(items[..2], [Ok(1), Ok(2)]));
+    assert!(matches!(
+        items[2],
+        Err(crate::Error::Sqlx(sqlx::Error::RowNotFound))
+    ));
+
+    let first = producer_stream(tracing::Span::none(), |sender| {
+        forward_stream(stream::iter((0..).map(Ok::<_, sqlx::Error>)), sender)
+    })
+    .take(3)
+    .collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(first.len(), 3);
+}
+
+#[tokio::test]
+async fn test_instrument_rows() {
+    use crate::utils::instrument_rows;
+    use futures::{StreamExt, stream};
+
+    let rows = stream::iter(vec![Ok(1), Err(sqlx::Error::RowNotFound), Ok(2)]);
+    let items = instrument_rows(tracing::Span::none(), rows)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(items.len(), 3);
+    assert!(matches!(items[0], Ok(1)));
+    assert!(matches!(
+        items[1],
+        Err(crate::Error::Sqlx(sqlx::Error::RowNotFound))
+    ));
+    assert!(matches!(items[2], Ok(2)));
+}
+
+#[test]
+fn test_replace_select_list() {
+    use crate::utils::replace_select_list;
+
+    assert_eq!(
+        replace_select_list("SELECT * FROM users WHERE id = ?", "COUNT(*)").as_deref(),
+        Some("SELECT COUNT(*) FROM users WHERE id = ?")
+    );
+    assert_eq!(
+        replace_select_list(
+            "select id, (SELECT name FROM teams) AS team from users ",
+            "1"
+        )
+        .as_deref(),
+        Some("SELECT 1 from users ")
+    );
+    assert_eq!(
+        replace_select_list("SELECT 'a from b' AS x FROM users", "1").as_deref(),
+        Some("SELECT 1 FROM users")
+    );
+
+    assert_eq!(
+        replace_select_list("SELECT DISTINCT name FROM users", "1"),
+        None
+    );
+    assert_eq!(
+        replace_select_list("SELECT name FROM users GROUP BY name", "1"),
+        None
+    );
+    assert_eq!(
+        replace_select_list("SELECT * FROM users ORDER BY name LIMIT 5", "1"),
+        None
+    );
+    assert_eq!(
+        replace_select_list("WITH a AS (SELECT 1) SELECT * FROM a", "1"),
+        None
+    );
+    assert_eq!(replace_select_list("SELECT 1", "1"), None);
+}
+
+#[test]
+fn test_filter_grouping() {
+    use crate::filter::{Filter, Raw, equals, greater_than, less_than};
+    use crate::traits::SqlFilter;
+    use crate::types::QueryBuilder;
+
+    fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+        let mut builder = QueryBuilder::new("");
+        filter.apply_filter(&mut builder);
+        builder.into_sql()
+    }
+
+    let a = || equals("a", Some(1));
+    let b = || greater_than("b", Some(2));
+    let c = || less_than("c", Some(3));
+    let none = || equals::<i32>("n", None);
+
+    assert_eq!(render(a()), "a = ?");
+    assert_eq!(render(a().and(b())), "(a = ? AND b > ?)");
+    assert_eq!(render(a().or(b())), "(a = ? OR b > ?)");
+
+    // The grouping follows the construction, not the SQL precedence of `AND` over `OR`
+    assert_eq!(render(a().or(b()).and(c())), "((a = ? OR b > ?) AND c < ?)");
+    assert_eq!(render(a().and(b()).or(c())), "((a = ? AND b > ?) OR c < ?)");
+    assert_eq!(render(a().or(b().and(c()))), "(a = ? OR (b > ? AND c < ?))");
+    assert_eq!(render(a().and(b().or(c()))), "(a = ? AND (b > ? OR c < ?))");
+    assert_eq!(
+        render(a().or(b()).and(c().or(a()))),
+        "((a = ? OR b > ?) AND (c < ? OR a = ?))"
+    );
+
+    assert_eq!(render(a().not()), "NOT (a = ?)");
+    assert_eq!(render(a().and(b()).not()), "NOT ((a = ? AND b > ?))");
+    assert_eq!(render(a().and(b().not())), "(a = ? AND NOT (b > ?))");
+    assert_eq!(render(a().not().or(b())), "(NOT (a = ?) OR b > ?)");
+
+    // Filters that do not apply leave no empty groups behind
+    assert_eq!(render(a().and(none())), "a = ?");
+    assert_eq!(render(none().or(b())), "b > ?");
+    assert_eq!(render(a().or(b()).and(none())), "(a = ? OR b > ?)");
+    assert_eq!(render(none().and(none()).not()), "");
+    assert!(!none().or(none()).should_apply_filter());
+
+    assert_eq!(
+        render(Filter::new(Raw::unsafe_raw("x > 1 OR y")).not()),
+        "NOT ((x > 1 OR y))"
+    );
+    assert_eq!(
+        render(Filter::new(Raw::unsafe_raw("x > 1 OR y")).and(c())),
+        "((x > 1 OR y) AND c < ?)"
+    );
+    assert_eq!(
+        render(a().or(Raw::unsafe_raw("x AND y"))),
+        "(a = ? OR (x AND y))"
+    );
+    assert_eq!(render(Raw::unsafe_raw("")), "");
+}
+
+#[test]
+fn test_null_and_between_filters() {
+    use crate::filter::{between, equals, is_not_null, is_null};
+    use crate::traits::SqlFilter;
+    use crate::types::QueryBuilder;
+
+    fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+        let mut builder = QueryBuilder::new("");
+        filter.apply_filter(&mut builder);
+        builder.into_sql()
+    }
+
+    assert_eq!(
+        render(is_null("deleted_at", Some(true))),
+        "deleted_at IS NULL"
+    );
+    assert_eq!(
+        render(is_null("deleted_at", Some(false))),
+        "deleted_at IS NOT NULL"
+    );
+    assert_eq!(
+        render(is_not_null("archived", Some(true))),
+        "archived IS NOT NULL"
+    );
+    assert_eq!(
+        render(is_not_null("archived", Some(false))),
+        "archived IS NULL"
+    );
+    assert!(!is_null("deleted_at", None).should_apply_filter());
+
+    assert_eq!(
+        render(between("age", Some((18, 65)))),
+        "age BETWEEN ? AND ?"
+    );
+    assert!(!between::<i32, i32>("age", None).should_apply_filter());
+
+    assert_eq!(
+        render(between("age", Some((18, 65))).and(equals("name", Some("Alice")))),
+        "(age BETWEEN ? AND ? AND name = ?)"
+    );
+}
+
+#[test]
+fn test_boxed_filters() {
+    use crate::filter::{BoxedFilter, all_of, any_of, equals, greater_than, is_null};
+    use crate::traits::SqlFilter;
+    use crate::types::QueryBuilder;
+
+    fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+        let mut builder = QueryBuilder::new("");
+        filter.apply_filter(&mut builder);
+        builder.into_sql()
+    }
+
+    let ids = |ids: Vec<i32>| any_of(ids.into_iter().map(|id| equals("id", Some(id))));
+
+    assert_eq!(render(ids(vec![1, 2, 3])), "(id = ? OR id = ? OR id = ?)");
+    assert_eq!(render(ids(vec![1])), "id = ?");
+    assert!(!ids(vec![]).should_apply_filter());
+
+    let mut filters: Vec<BoxedFilter> = Vec::new();
+
+    for (idx, age) in [Some(18), None, Some(21)].into_iter().enumerate() {
+        if idx == 1 {
+            filters.push(is_null("deleted_at", Some(true)).boxed());
+        }
+
+        filters.push(greater_than("age", age).boxed());
+    }
+
+    filters.push(ids(vec![1, 2]).boxed());
+
+    assert_eq!(
+        render(all_of(filters)),
+        "(age > ? AND deleted_at IS NULL AND age > ? AND (id = ? OR id = ?))"
+    );
+
+    assert_eq!(
+        render(ids(vec![1, 2]).and(all_of([equals::<i32>("a", None)]))),
+        "(id = ? OR id = ?)"
+    );
+
+    let boxed = BoxedFilter::new(ids(vec![1]).or(equals("b", Some(2))));
+    assert!(boxed.should_apply_filter());
+    assert_eq!(render(boxed), "(id = ? OR b = ?)");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_json_filter() {
+    use crate::filter::{ColumnWhitelist, JsonFilter, ValueKind};
+    use crate::traits::SqlFilter;
+    use crate::types::QueryBuilder;
+
+    let whitelist = ColumnWhitelist::new()
+        .column("age", ValueKind::Int)
+        .column("deleted_at", ValueKind::Text)
+        .column_as("name", "user_name", ValueKind::Text);
+
+    let render = |json: &str| -> crate::Result<String> {
+        let filter = serde_json::from_str::<JsonFilter>(json)
+            .unwrap()
+            .into_filter(&whitelist)?;
+
+        let mut builder = QueryBuilder::new("");
+        filter.apply_filter(&mut builder);
+        Ok(builder.into_sql())
+    };
+
+    assert_eq!(
+        render(
+            r#"{"and": [
+                {"field": "age", "op": ">=", "value": 18},
+                {"or": [
+                    {"field": "name", "op": "like", "value": "A%"},
+                    {"not": {"field": "deleted_at", "op": "is_null"}}
+                ]}
+            ]}"#
+        )
+        .unwrap(),
+        "(age >= ? AND (user_name LIKE ? OR NOT (deleted_at IS NULL)))"
+    );
+    assert_eq!(
+        render(r#"{"field": "age", "op": "IN", "value": [1, 2, 3]}"#).unwrap(),
+        "age IN (?, ?, ?)"
+    );
+    assert_eq!(
+        render(r#"{"field": "age", "op": "between", "value": [18, 65]}"#).unwrap(),
+        "age BETWEEN ? AND ?"
+    );
+    assert_eq!(
+        render(r#"{"field": "deleted_at", "op": "is_null", "value": false}"#).unwrap(),
+        "deleted_at IS NOT NULL"
+    );
+
+    // Anything outside of the whitelist or not matching the column is rejected
+    assert!(render(r#"{"field": "password", "op": "=", "value": "x"}"#).is_err());
+    assert!(render(r#"{"field": "user_name", "op": "=", "value": "x"}"#).is_err());
+    assert!(render(r#"{"field": "age", "op": "=", "value": "18"}"#).is_err());
+    assert!(render(r#"{"field": "age", "op": "like", "value": 18}"#).is_err());
+    assert!(render(r#"{"field": "age", "op": "in", "value": []}"#).is_err());
+    assert!(render(r#"{"field": "age", "op": "between", "value": [1]}"#).is_err());
+    assert!(render(r#"{"field": "age", "op": "=", "value": null}"#).is_err());
+
+    // Empty groups would match every row, even when negated
+    assert!(render(r#"{"and": []}"#).is_err());
+    assert!(render(r#"{"or": []}"#).is_err());
+    assert!(render(r#"{"not": {"or": []}}"#).is_err());
+    assert!(render(r#"{"and": [{"field": "age", "op": ">", "value": 1}, {"or": []}]}"#).is_err());
+
+    assert!(serde_json::from_str::<JsonFilter>(r#"{"field": "age", "op": "~"}"#).is_err());
+}
+
+#[test]
+#[cfg(feature = "any")]
+fn test_render_filter() {
+    use crate::filter::{Raw, between, equals, is_null, like};
+    use crate::traits::SqlFilter;
+
+    let rendered = equals("id", Some(7))
+        .and(like("name", Some("A%")))
+        .and(between("score", Some((1.5, 2.5))))
+        .render();
+
+    assert_eq!(
+        rendered.sql,
+        "((id = ? AND name LIKE ?) AND score BETWEEN ? AND ?)"
+    );
+    assert_eq!(rendered.arguments, ["7", "\"A%\"", "1.5", "2.5"]);
+    assert_eq!(
+        rendered.to_string(),
+        "((id = ? AND name LIKE ?) AND score BETWEEN ? AND ?) [7, \"A%\", 1.5, 2.5]"
+    );
+
+    let rendered = is_null("deleted_at", Some(true))
+        .or(equals("active", Some(true)))
+        .render();
+
+    assert_eq!(rendered.sql, "(deleted_at IS NULL OR active = ?)");
+    assert_eq!(rendered.arguments, ["true"]);
+
+    let rendered = Raw::unsafe_raw("1 = 1").render();
+    assert_eq!(rendered.to_string(), "(1 = 1) []");
+}
+
+#[test]
+#[cfg(feature = "any")]
+fn test_render_unrecorded_bind() {
+    use crate::filter::{equals, push_bind};
+    use crate::traits::SqlFilter;
+    use crate::types::{Database, QueryBuilder};
+
+    struct Adult(bool);
+
+    impl<'args> SqlFilter<'args> for Adult {
+        fn apply_filter(self, builder: &mut QueryBuilder<'args, Database>) {
+            builder.push("age >= ");
+
+            if self.0 {
+                push_bind(builder, 18);
+            } else {
+                builder.push_bind(18);
+            }
+        }
+
+        fn should_apply_filter(&self) -> bool {
+            true
+        }
+    }
+
+    let rendered = equals("name", Some("Alice")).and(Adult(true)).render();
+    assert_eq!(rendered.arguments, ["\"Alice\"", "18"]);
+
+    // Values bound on the builder directly can not be matched to their placeholders
+    let rendered = equals("name", Some("Alice")).and(Adult(false)).render();
+    assert_eq!(rendered.sql, "(name = ? AND age >= ?)");
+    assert_eq!(rendered.arguments, ["<value>", "<value>"]);
+}
+
+#[test]
+#[cfg(feature = "any")]
+fn test_subquery_filters() {
+    use crate::filter::{Raw, Subquery, equals, equals_raw, exists, in_subquery};
+    use crate::traits::SqlFilter;
+
+    let rendered = equals("active", Some(true))
+        .and(in_subquery(
+            "id",
+            Subquery::new(
+                "SELECT customer_id FROM orders",
+                equals("state", Some("open")),
+            ),
+        ))
+        .and(equals("country", Some("SE")))
+        .render();
+
+    assert_eq!(
+        rendered.sql,
+        "((active = ? AND id IN (SELECT customer_id FROM orders WHERE state = ?)) AND country = ?)"
+    );
+    assert_eq!(rendered.arguments, ["true", "\"open\"", "\"SE\""]);
+
+    let rendered = exists(Subquery::new(
+        "SELECT 1 FROM orders o",
+        equals_raw("o.customer_id", Raw::unsafe_raw("c.id")),
+    ))
+    .not()
+    .render();
+
+    assert_eq!(
+        rendered.sql,
+        "NOT (EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id))"
+    );
+
+    // A subquery without conditions still restricts the outer query
+    let filter = in_subquery(
+        "id",
+        Subquery::new(
+            "SELECT customer_id FROM orders",
+            equals::<i64>("state", None),
+        ),
+    );
+
+    assert!(filter.should_apply_filter());
+    assert_eq!(
+        filter.render().sql,
+        "id IN (SELECT customer_id FROM orders)"
+    );
+}
+
+#[test]
+#[cfg(feature = "any")]
+fn test_in_values_filters() {
+    use crate::filter::{in_values, in_values_list, not_in_values_list};
+    use crate::traits::SqlFilter;
+
+    let rendered = in_values("id", [1, 2, 3]).render();
+    assert_eq!(rendered.sql, "id IN (?, ?, ?)");
+    assert_eq!(rendered.arguments, ["1", "2", "3"]);
+
+    // `in_values` skips an empty list, while the `_list` variants only skip a missing one
+    assert!(!in_values("id", Vec::<i32>::new()).should_apply_filter());
+    assert!(!in_values_list("id", None::<Vec<i32>>).should_apply_filter());
+    assert_eq!(
+        in_values_list("id", Some(Vec::<i32>::new())).render().sql,
+        "1 = 0"
+    );
+    assert_eq!(
+        not_in_values_list("id", Some(Vec::<i32>::new()))
+            .render()
+            .sql,
+        "1 = 1"
+    );
+}
+
+#[test]
+fn test_identifier() {
+    use crate::filter::Identifier;
+    use crate::types::Dialect;
+
+    for valid in ["name", "_name2", "users.name", "public.users.name"] {
+        assert!(Identifier::try_new(valid).is_ok(), "{valid}");
+    }
+
+    for invalid in [
+        "",
+        "2name",
+        "users.",
+        ".name",
+        "a.b.c.d",
+        "name; DROP TABLE users",
+        "\"name\"",
+        "na-me",
+        "LOWER(name)",
+    ] {
+        assert!(Identifier::try_new(invalid).is_err(), "{invalid}");
+    }
+
+    let name = Identifier::new("users.name");
+
+    assert_eq!(name.quoted(Dialect::Postgres), "\"users\".\"name\"");
+    assert_eq!(name.quoted(Dialect::Sqlite), "\"users\".\"name\"");
+    assert_eq!(name.quoted(Dialect::MySql), "`users`.`name`");
+    assert_eq!(Dialect::MySql.quote_identifier("a`b"), "`a``b`");
+
+    // Names known only at runtime
+    let column = format!("{}.{}", "orders", "total");
+    let owned = Identifier::try_new(column).unwrap();
+
+    assert_eq!(owned.as_str(), "orders.total");
+    assert_eq!(owned.quoted(Dialect::Postgres), "\"orders\".\"total\"");
+    assert!(Identifier::try_new(String::from("total; --")).is_err());
+}
+
+#[test]
+#[cfg(feature = "any")]
+fn test_identifier_filters() {
+    use crate::filter::{Identifier, equals, in_values};
+    use crate::traits::SqlFilter;
+    use crate::types::{Database, Dialect, QueryBuilder};
+
+    let filter = || equals(Identifier::new("u.name"), Some("Alice")).and(in_values("id", [1, 2]));
+
+    let rendered = Dialect::Postgres.scope(|| filter().render());
+    assert_eq!(rendered.sql, "(\"u\".\"name\" = ? AND id IN (?, ?))");
+
+    let rendered = Dialect::MySql.scope(|| filter().render());
+    assert_eq!(rendered.sql, "(`u`.`name` = ? AND id IN (?, ?))");
+
+    // No pool is set in the tests, so the identifiers are quoted like in ANSI SQL
+    assert!(Dialect::current().is_err());
+    assert_eq!(
+        filter().render().sql,
+        "(\"u\".\"name\" = ? AND id IN (?, ?))"
+    );
+
+    let mut builder = QueryBuilder::<Database>::new("SELECT * FROM users u WHERE ");
+    filter().apply_filter(&mut builder);
+    assert_eq!(
+        builder.sql(),
+        "SELECT * FROM users u WHERE (\"u\".\"name\" = ? AND id IN (?, ?))"
+    );
+}
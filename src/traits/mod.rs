@@ -10,6 +10,7 @@ use crate::mod_def;
 
 mod_def! {
     pub mod model;
+    pub mod table;
     pub mod sql_filter;
     pub mod repository;
 }
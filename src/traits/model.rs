@@ -110,6 +110,15 @@ pub trait Model: Send + Sync {
     fn has_id(&self) -> bool {
         self.get_id().is_some()
     }
+
+    /// Returns whether the model can be inserted as a new row.
+    ///
+    /// Defaults to the model having no id. Models whose key is set by the application instead of
+    /// assigned on insert, like composite keys, always hold an id and return `true`, leaving
+    /// duplicates to the key's constraint in the database.
+    fn is_new(&self) -> bool {
+        !self.has_id()
+    }
 }
 
 impl<M> Model for Vec<M>
@@ -2,7 +2,7 @@
 
 use crate::prelude::{Database, SqlFilter};
 use crate::traits::{Model, Repository};
-use crate::types::Query;
+use crate::types::{Dialect, Query};
 use crate::utils::{BatchOperator, DEFAULT_BATCH_SIZE};
 use sqlx::{Executor, QueryBuilder};
 
@@ -334,7 +334,8 @@ pub trait DeleteRepository<M: Model>: Repository<M> {
             });
         }
 
-        Self::delete_by_filter_query(filter)
+        Dialect::of(self.pool())?
+            .scope(|| Self::delete_by_filter_query(filter))
             .build()
             .execute(tx)
             .await?;
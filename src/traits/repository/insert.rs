@@ -1,10 +1,10 @@
 //! Trait for adding insert capabilities to a repository
 
 use crate::prelude::Database;
-use crate::traits::{Model, Repository};
-use crate::types::Query;
+use crate::traits::{Model, Repository, Table};
+use crate::types::{Dialect, Query};
 use crate::utils::{BatchOperator, DEFAULT_BATCH_SIZE};
-use sqlx::Executor;
+use sqlx::{Acquire, Database as DatabaseTrait, Execute, Executor, FromRow};
 
 /// Trait for repositories that can insert new records into the database.
 ///
@@ -90,6 +90,11 @@ use sqlx::Executor;
 ///    - [`insert`](InsertableRepository::insert) - Inserts a single model
 ///    - [`insert_many`](InsertableRepository::insert_many) - Inserts multiple models using the default batch size
 ///    - [`insert_batch`](InsertableRepository::insert_batch) - Inserts multiple models with a custom batch size
+///    - [`insert_returning`](InsertableRepository::insert_returning) and the other `_returning` variants - Inserts
+///      models and reads back the stored rows, including generated keys and defaulted columns, these
+///      require the model to implement [`Table`] and [`FromRow`]
+///    - [`insert_bulk`](InsertableRepository::insert_bulk) and the other `_bulk` variants - Inserts many
+///      models with multi-row `INSERT` statements, these require the model to implement [`Table`]
 /// 3. All batch operations use transactions to ensure data consistency
 /// 4. Performance is optimized through batching and connection pooling
 #[diagnostic::on_unimplemented(
@@ -146,7 +151,7 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
     ///
     /// # Panics
     ///
-    /// The method will panic if an ID is present, but it will only do so in debug mode to avoid
+    /// The method will panic if the model is not [`new`](Model::is_new), but it will only do so in debug mode to avoid
     /// performance issues. This is so that we don't insert a duplicate key, if this is the desired behavior you want you can enable the feature `insert_duplicate`
     #[inline(always)]
     #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert", err))]
@@ -157,7 +162,7 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
         E: Executor<'c, Database = Database> + Send,
     {
         #[cfg(not(feature = "insert_duplicate"))]
-        debug_assert!(model.get_id().is_none());
+        debug_assert!(model.is_new());
 
         Self::insert_query(&model).execute(tx).await?;
         Ok(model)
@@ -187,7 +192,7 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
     ///
     /// # Panics
     ///
-    /// The method will panic if an ID is present, but it will only do so in debug mode to avoid
+    /// The method will panic if the model is not [`new`](Model::is_new), but it will only do so in debug mode to avoid
     /// performance issues. This is so that we don't insert a duplicate key, if this is the desired behavior you want you can enable the feature `insert_duplicate`
     #[inline(always)]
     #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert", err))]
@@ -197,7 +202,7 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
         E: Executor<'c, Database = Database> + Send,
     {
         #[cfg(not(feature = "insert_duplicate"))]
-        debug_assert!(model.get_id().is_none());
+        debug_assert!(model.is_new());
 
         Self::insert_query(model).execute(tx).await?;
         Ok(())
@@ -227,7 +232,7 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
     ///
     /// # Panics
     ///
-    /// The method will panic if an ID is present, but it will only do so in debug mode to avoid
+    /// The method will panic if the model is not [`new`](Model::is_new), but it will only do so in debug mode to avoid
     /// performance issues. This is so that we don't insert a duplicate key, if this is the desired behavior you want you can enable the feature `insert_duplicate`
     #[inline(always)]
     async fn insert(&self, model: M) -> crate::Result<M>
@@ -261,7 +266,7 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
     ///
     /// # Panics
     ///
-    /// The method will panic if an ID is present, but it will only do so in debug mode to avoid
+    /// The method will panic if the model is not [`new`](Model::is_new), but it will only do so in debug mode to avoid
     /// performance issues. This is so that we don't insert a duplicate key, if this is the desired behavior you want you can enable the feature `insert_duplicate`
     #[inline(always)]
     async fn insert_ref(&self, model: &M) -> crate::Result<()>
@@ -271,6 +276,123 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
         self.insert_ref_with_executor(self.pool(), model).await
     }
 
+    /// Persists a new model instance and reads back the stored row using the connection `tx`.
+    ///
+    /// Unlike [`insert_with_executor`](InsertableRepository::insert_with_executor) the returned
+    /// model is decoded from the database, so it contains any generated primary key and columns
+    /// filled in by defaults or triggers.
+    ///
+    /// On Postgres and SQLite `RETURNING` with the model's [`SELECT_COLUMNS`](Table::SELECT_COLUMNS)
+    /// is appended to the [`insert_query`](InsertableRepository::insert_query).
+    /// MySQL has no `RETURNING`, so the row is selected afterward on the same connection, by the
+    /// model's id if it has one and otherwise by `LAST_INSERT_ID()`, which requires an
+    /// `AUTO_INCREMENT` key.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx` - The connection, transaction or pool to use for the queries
+    /// * `model` - The model instance to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<M>`](crate::Result) - The inserted row if successful
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// async fn create_user(repo: &UserRepository, user: User) -> crate::Result<User> {
+    ///     let mut tx = repo.pool().begin().await?;
+    ///     let user = repo.insert_returning_with_executor(&mut *tx, user).await?;
+    ///     tx.commit().await?;
+    ///
+    ///     Ok(user)
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the model is not [`new`](Model::is_new), but it will only do so in debug mode to avoid
+    /// performance issues. This is so that we don't insert a duplicate key, if this is the desired behavior you want you can enable the feature `insert_duplicate`
+    #[inline(always)]
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_returning", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_returning"))]
+    async fn insert_returning_with_executor<'c, A>(&self, tx: A, model: M) -> crate::Result<M>
+    where
+        M: Table + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Unpin + 'async_trait,
+        A: Acquire<'c, Database = Database> + Send,
+    {
+        #[cfg(not(feature = "insert_duplicate"))]
+        debug_assert!(model.is_new());
+
+        let dialect = Dialect::of(self.pool())?;
+        let mut conn = tx.acquire().await?;
+        let mut query = Self::insert_query(&model);
+
+        if dialect.supports_returning() {
+            let sql = format!(
+                "{} RETURNING {}",
+                query.sql().trim_end().trim_end_matches(';'),
+                M::SELECT_COLUMNS
+            );
+            let arguments = query
+                .take_arguments()
+                .map_err(|err| crate::Error::Boxed(err))?
+                .unwrap_or_default();
+
+            return sqlx::query_as_with(&sql, arguments)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(Into::into);
+        }
+
+        query.execute(&mut *conn).await?;
+
+        match model.get_id() {
+            Some(id) => M::get_by_id_query(id).fetch_one(&mut *conn).await,
+            None => {
+                let sql = format!(
+                    "SELECT {} FROM {} WHERE {} = LAST_INSERT_ID()",
+                    M::SELECT_COLUMNS,
+                    M::TABLE_NAME,
+                    M::ID_COLUMNS[0]
+                );
+
+                sqlx::query_as(&sql).fetch_one(&mut *conn).await
+            }
+        }
+        .map_err(Into::into)
+    }
+
+    /// Persists a new model instance and reads back the stored row.
+    ///
+    /// This calls [`insert_returning_with_executor`](InsertableRepository::insert_returning_with_executor)
+    /// with the repository's connection pool.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The model instance to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<M>`](crate::Result) - The inserted row if successful
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// async fn create_user(repo: &UserRepository, user: User) -> crate::Result<i64> {
+    ///     let user = repo.insert_returning(user).await?;
+    ///
+    ///     Ok(user.id)
+    /// }
+    /// ```
+    #[inline(always)]
+    async fn insert_returning(&self, model: M) -> crate::Result<M>
+    where
+        M: Table + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Unpin + 'async_trait,
+    {
+        self.insert_returning_with_executor(self.pool(), model).await
+    }
+
     /// Inserts multiple models using the default batch size.
     ///
     /// This is a convenience wrapper around [`insert_batch`](InsertableRepository::insert_batch) that uses [`DEFAULT_BATCH_SIZE`].
@@ -345,4 +467,198 @@ pub trait InsertableRepository<M: Model>: Repository<M> {
 
         BatchOperator::<M, N>::execute_query(models, self.pool(), Self::insert_query).await
     }
+
+    /// Inserts multiple models using the default batch size and reads back the stored rows.
+    ///
+    /// This is a convenience wrapper around [`insert_batch_returning`](InsertableRepository::insert_batch_returning)
+    /// that uses [`DEFAULT_BATCH_SIZE`].
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - An iterator yielding model instances to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<Vec<M>>`](crate::Result) - The inserted rows in the same order as `models`
+    #[inline(always)]
+    async fn insert_many_returning<I>(&self, models: I) -> crate::Result<Vec<M>>
+    where
+        M: Table + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Unpin + 'async_trait,
+        I: IntoIterator<Item = M> + Send + 'async_trait,
+        I::IntoIter: Send,
+    {
+        <Self as InsertableRepository<M>>::insert_batch_returning::<DEFAULT_BATCH_SIZE, I>(
+            self, models,
+        )
+        .await
+    }
+
+    /// Performs a batched insertion with a specified batch size, reading back the stored rows.
+    ///
+    /// Each batch of `N` models is inserted in its own transaction with
+    /// [`insert_returning_with_executor`](InsertableRepository::insert_returning_with_executor).
+    /// Batches are processed one after another so the returned rows keep the order of the input.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `N` - The size of each batch to process
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - An iterator yielding model instances to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<Vec<M>>`](crate::Result) - The inserted rows in the same order as `models`
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_batch_returning", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_batch_returning"))]
+    #[inline(always)]
+    async fn insert_batch_returning<const N: usize, I>(&self, models: I) -> crate::Result<Vec<M>>
+    where
+        M: Table + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Unpin + 'async_trait,
+        I: IntoIterator<Item = M> + Send + 'async_trait,
+        I::IntoIter: Send,
+    {
+        let span = tracing::Span::current();
+        span.record("BATCH_SIZE", N);
+
+        let mut models = models.into_iter().peekable();
+        let mut inserted = Vec::new();
+
+        while models.peek().is_some() {
+            let mut tx = self.pool().begin().await?;
+
+            for model in models.by_ref().take(N) {
+                inserted.push(self.insert_returning_with_executor(&mut *tx, model).await?);
+            }
+
+            tx.commit().await?;
+        }
+
+        Ok(inserted)
+    }
+
+    /// Inserts every model in `models` using as few multi-row `INSERT` statements as possible.
+    ///
+    /// The statements are built with [`Table::insert_many_query`], putting as many rows in each
+    /// statement as the bind parameter limit of the database allows, see [`Dialect::max_rows`].
+    /// Models without any insertable columns can not be expressed with a `VALUES` list and fall
+    /// back to one [`insert_query`](InsertableRepository::insert_query) per model.
+    ///
+    /// All statements run on the connection `tx`, use a transaction if the insert should either
+    /// succeed or fail as a whole.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx` - The connection, transaction or pool to use for the queries
+    /// * `models` - The models to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<()>`](crate::Result) - Success if all statements were executed, or an error if any failed,
+    ///   or if a single row has more values than the database allows in a statement
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the model is not [`new`](Model::is_new), but it will only do so in debug mode to avoid
+    /// performance issues. This is so that we don't insert a duplicate key, if this is the desired behavior you want you can enable the feature `insert_duplicate`
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_bulk", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_bulk"))]
+    #[inline(always)]
+    async fn insert_bulk_with_executor<'c, A>(&self, tx: A, models: &[M]) -> crate::Result<()>
+    where
+        M: Table,
+        A: Acquire<'c, Database = Database> + Send,
+    {
+        #[cfg(not(feature = "insert_duplicate"))]
+        debug_assert!(models.iter().all(Model::is_new));
+
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        let rows = Dialect::of(self.pool())?.checked_max_rows(M::INSERT_COLUMNS.len())?;
+        let mut conn = tx.acquire().await?;
+
+        if M::INSERT_COLUMNS.is_empty() {
+            for model in models {
+                Self::insert_query(model).execute(&mut *conn).await?;
+            }
+
+            return Ok(());
+        }
+
+        for chunk in models.chunks(rows) {
+            M::insert_many_query(chunk)
+                .build()
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Inserts multiple models with multi-row `INSERT` statements using the default batch size.
+    ///
+    /// This is a convenience wrapper around [`insert_bulk_batch`](InsertableRepository::insert_bulk_batch)
+    /// that uses [`DEFAULT_BATCH_SIZE`].
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - An iterator yielding model instances to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<()>`](crate::Result) - Success if all insertions were executed, or an error if any operation failed
+    #[inline(always)]
+    async fn insert_bulk<I>(&self, models: I) -> crate::Result<()>
+    where
+        M: Table + 'async_trait,
+        I: IntoIterator<Item = M> + Send + 'async_trait,
+        I::IntoIter: Send,
+    {
+        <Self as InsertableRepository<M>>::insert_bulk_batch::<DEFAULT_BATCH_SIZE, I>(self, models)
+            .await
+    }
+
+    /// Performs a batched insertion with multi-row `INSERT` statements.
+    ///
+    /// Unlike [`insert_batch`](InsertableRepository::insert_batch), which executes one statement
+    /// per model, each batch of `N` models is inserted by
+    /// [`insert_bulk_with_executor`](InsertableRepository::insert_bulk_with_executor) in its own
+    /// transaction, so a batch only needs more than one round-trip if it exceeds the bind
+    /// parameter limit of the database.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `N` - The size of each batch to process
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - An iterator yielding model instances to insert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<()>`](crate::Result) - Success if all batches were processed, or an error if any operation failed
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_bulk_batch", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "insert_bulk_batch"))]
+    #[inline(always)]
+    async fn insert_bulk_batch<const N: usize, I>(&self, models: I) -> crate::Result<()>
+    where
+        M: Table + 'async_trait,
+        I: IntoIterator<Item = M> + Send + 'async_trait,
+        I::IntoIter: Send,
+    {
+        let span = tracing::Span::current();
+        span.record("BATCH_SIZE", N);
+
+        BatchOperator::<M, N>::execute_batch(models, |batch| async move {
+            let mut tx = self.pool().begin().await?;
+            self.insert_bulk_with_executor(&mut *tx, &batch).await?;
+            tx.commit().await?;
+
+            Ok(())
+        })
+        .await
+    }
 }
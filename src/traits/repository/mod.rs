@@ -4,6 +4,7 @@ mod_def! {!export
     pub(crate) mod insert;
     pub(crate) mod update;
     pub(crate) mod save;
+    pub(crate) mod upsert;
     pub(crate) mod select;
     pub(crate) mod delete;
     pub(crate) mod transactions;
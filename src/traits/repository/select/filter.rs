@@ -1,9 +1,15 @@
 //! Filter related traits for repositories
 
+use crate::filter::render::capture_arguments;
 use crate::traits::{Model, Repository, SqlFilter};
-use crate::types::Database;
+use crate::types::{Database, Dialect, Page, PageRequest};
+use crate::utils::{
+    exists_from_row, forward_stream, producer_stream, push_clauses, push_clauses_with, replace_select_list,
+    take_query,
+};
 use cfg_if::cfg_if;
-use sqlx::{Database as DatabaseTrait, Executor, FromRow, QueryBuilder};
+use futures::{Stream, future, stream};
+use sqlx::{Acquire, Database as DatabaseTrait, Executor, FromRow, QueryBuilder};
 use std::fmt::Debug;
 
 macro_rules! filter_repository_methods {
@@ -28,7 +34,7 @@ macro_rules! filter_repository_methods {
         ///
         /// * [`crate::Result<Vec<M>>`] - A Result containing all matching models
         #[inline(always)]
-        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_by_filter", $($err, )?)]
+        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_by_filter", fields(query = tracing::field::Empty, arguments = tracing::field::Empty), $($err, )?)]
         async fn get_all_by_any_filter_with_executor<'a, 'c, F, E>(
             &'a self,
             tx: E,
@@ -38,7 +44,12 @@ macro_rules! filter_repository_methods {
             F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
             E: Executor<'c, Database = Database> + 'a,
         {
-            Self::prepare_filter_query(filter).build_query_as().fetch_all(tx).await.map_err(Into::into)
+            let dialect = Dialect::of(self.pool())?;
+            let (mut builder, captured) =
+                capture_arguments(|| dialect.scope(|| Self::prepare_filter_query(filter)));
+            let (sql, arguments) = take_query(&mut builder, &captured)?;
+
+            sqlx::query_as_with(sql, arguments).fetch_all(tx).await.map_err(Into::into)
         }
 
         /// Retrieves exactly one record matching the specified filter using a custom executor.
@@ -63,7 +74,7 @@ macro_rules! filter_repository_methods {
         ///   - Error if no records match
         ///   - Error if multiple records match
         #[inline(always)]
-        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_by_filter", $($err, )?)]
+        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_by_filter", fields(query = tracing::field::Empty, arguments = tracing::field::Empty), $($err, )?)]
         async fn get_one_by_any_filter_with_executor<'a, 'c, F, E>(
             &'a self,
             tx: E,
@@ -73,7 +84,12 @@ macro_rules! filter_repository_methods {
             F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
             E: Executor<'c, Database = Database> + 'a,
         {
-            Self::prepare_filter_query(filter).build_query_as().fetch_one(tx).await.map_err(Into::into)
+            let dialect = Dialect::of(self.pool())?;
+            let (mut builder, captured) =
+                capture_arguments(|| dialect.scope(|| Self::prepare_filter_query(filter)));
+            let (sql, arguments) = take_query(&mut builder, &captured)?;
+
+            sqlx::query_as_with(sql, arguments).fetch_one(tx).await.map_err(Into::into)
         }
 
         /// Retrieves an optional record matching the specified filter using a custom executor.
@@ -100,7 +116,7 @@ macro_rules! filter_repository_methods {
         ///   - `Some(model)` if exactly one record matches
         ///   - Error if multiple records match
         #[inline(always)]
-        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_by_filter", $($err, )?)]
+        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_by_filter", fields(query = tracing::field::Empty, arguments = tracing::field::Empty), $($err, )?)]
         async fn get_optional_by_any_filter_with_executor<'a, 'c, F, E>(
             &'a self,
             tx: E,
@@ -110,7 +126,68 @@ macro_rules! filter_repository_methods {
             F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
             E: Executor<'c, Database = Database> + 'a,
         {
-            Self::prepare_filter_query(filter).build_query_as().fetch_optional(tx).await.map_err(Into::into)
+            let dialect = Dialect::of(self.pool())?;
+            let (mut builder, captured) =
+                capture_arguments(|| dialect.scope(|| Self::prepare_filter_query(filter)));
+            let (sql, arguments) = take_query(&mut builder, &captured)?;
+
+            sqlx::query_as_with(sql, arguments).fetch_optional(tx).await.map_err(Into::into)
+        }
+
+        /// Retrieves a single page of the records matching the specified filter using a custom connection.
+        ///
+        /// Two queries are executed on the same connection, one counting every matching record
+        /// and one selecting the records of the page, see [`PageRequest`] for the supported
+        /// pagination modes.
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter and connection
+        /// * `F` - The filter type, must implement [`SqlFilter`] and [`Clone`] as it is applied to both queries
+        /// * `A` - The connection type, must implement [`Acquire`]
+        ///
+        /// # Parameters
+        ///
+        /// * `tx` - The connection, transaction or pool to use for the queries
+        /// * `filter` - The filter to apply
+        /// * `request` - The page to retrieve
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<Page<M>>`] - A Result containing the records of the page, the total
+        ///   number of matching records and the request for the next page
+        #[inline(always)]
+        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "get_page_by_filter", $($err, )?)]
+        async fn get_page_by_any_filter_with_executor<'a, 'c, F, A>(
+            &'a self,
+            tx: A,
+            filter: F,
+            request: PageRequest,
+        ) -> crate::Result<Page<M>>
+        where
+            F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Clone + Send + 'a,
+            A: Acquire<'c, Database = Database> + Send + 'a,
+        {
+            let dialect = Dialect::of(self.pool())?;
+            let mut conn = tx.acquire().await?;
+
+            let total: i64 = dialect.scope(|| Self::prepare_count_query(filter.clone()))?
+                .build_query_scalar()
+                .fetch_one(&mut *conn)
+                .await?;
+
+            let rows = dialect.scope(|| Self::prepare_page_query(filter, &request))?
+                .build()
+                .fetch_all(&mut *conn)
+                .await?;
+
+            let (items, next) = request.next_page(rows, |row| M::from_row(row))?;
+
+            Ok(Page {
+                items,
+                total: total as u64,
+                next,
+            })
         }
 
         /// Retrieves all records matching the specified filter using the repository's connection pool.
@@ -204,6 +281,260 @@ macro_rules! filter_repository_methods {
             let pool = self.pool();
             self.get_optional_by_any_filter_with_executor(pool, filter).await
         }
+
+        /// Retrieves a single page of the records matching the specified filter using the repository's connection pool.
+        ///
+        /// This is a convenience method that uses the repository's own connection pool
+        /// as the connection for [`get_page_by_any_filter_with_executor`](FilterRepository::get_page_by_any_filter_with_executor).
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter
+        /// * `F` - The filter type, must implement [`SqlFilter`] and [`Clone`]
+        ///
+        /// # Parameters
+        ///
+        /// * `filter` - The filter to apply
+        /// * `request` - The page to retrieve
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<Page<M>>`] - A Result containing the records of the page, the total
+        ///   number of matching records and the request for the next page
+        #[inline(always)]
+        async fn get_page_by_any_filter<'a, F>(
+            &'a self,
+            filter: F,
+            request: PageRequest,
+        ) -> crate::Result<Page<M>>
+        where
+            F: for<'c> SqlFilter<'c, Database> $(+ $debug)? + Clone + Send + 'a,
+        {
+            let pool = self.pool();
+            self.get_page_by_any_filter_with_executor(pool, filter, request).await
+        }
+
+        /// Counts the records matching the specified filter using a custom executor.
+        ///
+        /// The query is built by [`prepare_count_query`](FilterRepository::prepare_count_query),
+        /// so no records are fetched.
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter and executor
+        /// * `F` - The filter type, must implement [`SqlFilter`]
+        /// * `E` - The executor type, must implement [`Executor`]
+        ///
+        /// # Parameters
+        ///
+        /// * `tx` - The executor to use for the query
+        /// * `filter` - The filter to apply
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<u64>`] - A Result containing the number of matching records
+        #[inline(always)]
+        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "count_by_filter", $($err, )?)]
+        async fn count_by_any_filter_with_executor<'a, 'c, F, E>(
+            &'a self,
+            tx: E,
+            filter: F,
+        ) -> crate::Result<u64>
+        where
+            F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
+            E: Executor<'c, Database = Database> + 'a,
+        {
+            let count: i64 = Dialect::of(self.pool())?
+                .scope(|| Self::prepare_count_query(filter))?
+                .build_query_scalar()
+                .fetch_one(tx)
+                .await?;
+
+            Ok(count as u64)
+        }
+
+        /// Counts the records matching the specified filter using the repository's connection pool.
+        ///
+        /// This is a convenience method that uses the repository's own connection pool
+        /// as the executor for [`count_by_any_filter_with_executor`](FilterRepository::count_by_any_filter_with_executor).
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter
+        /// * `F` - The filter type, must implement [`SqlFilter`]
+        ///
+        /// # Parameters
+        ///
+        /// * `filter` - The filter to apply
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<u64>`] - A Result containing the number of matching records
+        #[inline(always)]
+        async fn count_by_any_filter<'a, F>(
+            &'a self,
+            filter: F,
+        ) -> crate::Result<u64>
+        where
+            F: for<'c> SqlFilter<'c, Database> $(+ $debug)? + Send + 'a,
+        {
+            let pool = self.pool();
+            self.count_by_any_filter_with_executor(pool, filter).await
+        }
+
+        /// Checks if any record matches the specified filter using a custom executor.
+        ///
+        /// The query is built by [`prepare_exists_query`](FilterRepository::prepare_exists_query),
+        /// letting the database stop at the first matching record.
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter and executor
+        /// * `F` - The filter type, must implement [`SqlFilter`]
+        /// * `E` - The executor type, must implement [`Executor`]
+        ///
+        /// # Parameters
+        ///
+        /// * `tx` - The executor to use for the query
+        /// * `filter` - The filter to apply
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<bool>`] - A Result containing `true` if at least one record matches
+        #[inline(always)]
+        #[tracing::instrument(skip($($ident),*), level = "debug", parent = &Self::repository_span(), name = "exists_by_filter", $($err, )?)]
+        async fn exists_by_any_filter_with_executor<'a, 'c, F, E>(
+            &'a self,
+            tx: E,
+            filter: F,
+        ) -> crate::Result<bool>
+        where
+            F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
+            E: Executor<'c, Database = Database> + 'a,
+        {
+            let row = Dialect::of(self.pool())?
+                .scope(|| Self::prepare_exists_query(filter))?
+                .build()
+                .fetch_one(tx)
+                .await?;
+
+            exists_from_row(&row)
+        }
+
+        /// Checks if any record matches the specified filter using the repository's connection pool.
+        ///
+        /// This is a convenience method that uses the repository's own connection pool
+        /// as the executor for [`exists_by_any_filter_with_executor`](FilterRepository::exists_by_any_filter_with_executor).
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter
+        /// * `F` - The filter type, must implement [`SqlFilter`]
+        ///
+        /// # Parameters
+        ///
+        /// * `filter` - The filter to apply
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<bool>`] - A Result containing `true` if at least one record matches
+        #[inline(always)]
+        async fn exists_by_any_filter<'a, F>(
+            &'a self,
+            filter: F,
+        ) -> crate::Result<bool>
+        where
+            F: for<'c> SqlFilter<'c, Database> $(+ $debug)? + Send + 'a,
+        {
+            let pool = self.pool();
+            self.exists_by_any_filter_with_executor(pool, filter).await
+        }
+
+        /// Streams the records matching the specified filter using a custom executor.
+        ///
+        /// Unlike [`get_all_by_any_filter_with_executor`](FilterRepository::get_all_by_any_filter_with_executor)
+        /// the records are decoded one at a time as the database returns them, so only a single
+        /// record is held in memory. The query runs in the same `get_by_filter` span.
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter and executor
+        /// * `F` - The filter type, must implement [`SqlFilter`]
+        /// * `E` - The executor type, must implement [`Executor`]
+        ///
+        /// # Parameters
+        ///
+        /// * `tx` - The executor to use for the query
+        /// * `filter` - The filter to apply
+        ///
+        /// # Returns
+        ///
+        /// * A [`Stream`] of the matching models, or the error that ended the query
+        #[inline]
+        fn stream_by_any_filter_with_executor<'a, 'c, F, E>(
+            &'a self,
+            tx: E,
+            filter: F,
+        ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+        where
+            M: 'a,
+            F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
+            E: Executor<'c, Database = Database> + 'a,
+            'c: 'a,
+        {
+            let span = tracing::debug_span!(
+                parent: &Self::repository_span(),
+                "get_by_filter",
+                $(filter = ?(&filter as &dyn $debug),)?
+                query = tracing::field::Empty,
+                arguments = tracing::field::Empty,
+            );
+
+            let dialect = Dialect::of(self.pool());
+
+            producer_stream(span, move |sender| async move {
+                let (mut builder, captured) = match dialect {
+                    Ok(dialect) => capture_arguments(|| dialect.scope(|| Self::prepare_filter_query(filter))),
+                    Err(error) => return forward_stream(stream::once(future::ready(Err::<M, _>(error))), sender).await,
+                };
+
+                match take_query(&mut builder, &captured) {
+                    Ok((sql, arguments)) => {
+                        forward_stream(sqlx::query_as_with(sql, arguments).fetch(tx), sender).await
+                    }
+                    Err(error) => forward_stream(stream::once(future::ready(Err::<M, _>(error))), sender).await,
+                }
+            })
+        }
+
+        /// Streams the records matching the specified filter using the repository's connection pool.
+        ///
+        /// This is a convenience method that uses the repository's own connection pool
+        /// as the executor for [`stream_by_any_filter_with_executor`](FilterRepository::stream_by_any_filter_with_executor).
+        ///
+        /// # Type Parameters
+        ///
+        /// * `'a` - The lifetime of the filter
+        /// * `F` - The filter type, must implement [`SqlFilter`]
+        ///
+        /// # Parameters
+        ///
+        /// * `filter` - The filter to apply
+        ///
+        /// # Returns
+        ///
+        /// * A [`Stream`] of the matching models, or the error that ended the query
+        #[inline]
+        fn stream_by_any_filter<'a, F>(
+            &'a self,
+            filter: F,
+        ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+        where
+            M: 'a,
+            F: for<'c> SqlFilter<'c, Database> $(+ $debug)? + Send + 'a,
+        {
+            self.stream_by_any_filter_with_executor(self.pool(), filter)
+        }
     };
 }
 
@@ -293,6 +624,14 @@ macro_rules! filter_repository_ext {
         /// 3. Optional Record:
         ///    - [`get_optional_by_filter`](FilterRepositoryExt::get_optional_by_filter) - Get a record if it exists
         ///    - [`get_optional_by_filter_with_executor`](FilterRepositoryExt::get_optional_by_filter_with_executor) - Same, but with a custom executor
+        ///
+        /// 4. Counting:
+        ///    - [`count_by_filter`](FilterRepositoryExt::count_by_filter) - Count the matching records
+        ///    - [`exists_by_filter`](FilterRepositoryExt::exists_by_filter) - Check if any record matches
+        ///
+        /// 5. Streams:
+        ///    - [`stream_by_filter`](FilterRepositoryExt::stream_by_filter) - Stream the matching records one at a time
+        ///    - [`stream_by_filter_with_executor`](FilterRepositoryExt::stream_by_filter_with_executor) - Same, but with a custom executor
         #[diagnostic::on_unimplemented(
             message = "Type `{Self}` cannot use `FilterRepositoryExt<{M}, {Filter}>` because it does not implement `FilterRepository<{M}>`",
             label = "this type needs to implement `FilterRepository<{M}>` first",
@@ -455,6 +794,120 @@ macro_rules! filter_repository_ext {
                 let pool = self.pool();
                 self.get_optional_by_filter_with_executor(pool, filter).await
             }
+
+            /// Retrieves a single page of the records matching the filter with a custom connection.
+            ///
+            /// See [`get_page_by_any_filter_with_executor`](FilterRepository::get_page_by_any_filter_with_executor).
+            #[inline(always)]
+            async fn get_page_by_filter_with_executor<'c, A>(
+                &self,
+                tx: A,
+                filter: Filter,
+                request: PageRequest,
+            ) -> crate::Result<Page<M>>
+            where
+                Filter: Clone,
+                A: Acquire<'c, Database = Database> + Send,
+            {
+                self.get_page_by_any_filter_with_executor(tx, filter, request).await
+            }
+
+            /// Retrieves a single page of the records matching the filter.
+            ///
+            /// See [`get_page_by_any_filter`](FilterRepository::get_page_by_any_filter).
+            #[inline(always)]
+            async fn get_page_by_filter(
+                &self,
+                filter: Filter,
+                request: PageRequest,
+            ) -> crate::Result<Page<M>>
+            where
+                Filter: Clone,
+            {
+                let pool = self.pool();
+                self.get_page_by_filter_with_executor(pool, filter, request).await
+            }
+
+            /// Counts the records matching the filter with a custom executor.
+            ///
+            /// See [`count_by_any_filter_with_executor`](FilterRepository::count_by_any_filter_with_executor).
+            #[inline(always)]
+            async fn count_by_filter_with_executor<'c, E>(
+                &self,
+                tx: E,
+                filter: Filter,
+            ) -> crate::Result<u64>
+            where
+                E: Executor<'c, Database = Database>,
+            {
+                self.count_by_any_filter_with_executor(tx, filter).await
+            }
+
+            /// Counts the records matching the filter.
+            ///
+            /// See [`count_by_any_filter`](FilterRepository::count_by_any_filter).
+            #[inline(always)]
+            async fn count_by_filter(&self, filter: Filter) -> crate::Result<u64> {
+                let pool = self.pool();
+                self.count_by_filter_with_executor(pool, filter).await
+            }
+
+            /// Checks if any record matches the filter with a custom executor.
+            ///
+            /// See [`exists_by_any_filter_with_executor`](FilterRepository::exists_by_any_filter_with_executor).
+            #[inline(always)]
+            async fn exists_by_filter_with_executor<'c, E>(
+                &self,
+                tx: E,
+                filter: Filter,
+            ) -> crate::Result<bool>
+            where
+                E: Executor<'c, Database = Database>,
+            {
+                self.exists_by_any_filter_with_executor(tx, filter).await
+            }
+
+            /// Checks if any record matches the filter.
+            ///
+            /// See [`exists_by_any_filter`](FilterRepository::exists_by_any_filter).
+            #[inline(always)]
+            async fn exists_by_filter(&self, filter: Filter) -> crate::Result<bool> {
+                let pool = self.pool();
+                self.exists_by_filter_with_executor(pool, filter).await
+            }
+
+            /// Streams the records matching the filter with a custom executor.
+            ///
+            /// See [`stream_by_any_filter_with_executor`](FilterRepository::stream_by_any_filter_with_executor).
+            #[inline]
+            fn stream_by_filter_with_executor<'a, 'c, E>(
+                &'a self,
+                tx: E,
+                filter: Filter,
+            ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+            where
+                M: 'a,
+                Filter: 'a,
+                E: Executor<'c, Database = Database> + 'a,
+                'c: 'a,
+            {
+                self.stream_by_any_filter_with_executor(tx, filter)
+            }
+
+            /// Streams the records matching the filter.
+            ///
+            /// See [`stream_by_any_filter`](FilterRepository::stream_by_any_filter).
+            #[inline]
+            fn stream_by_filter<'a>(
+                &'a self,
+                filter: Filter,
+            ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+            where
+                M: 'a,
+                Filter: 'a,
+            {
+                self.stream_by_filter_with_executor(self.pool(), filter)
+            }
         }
 
         #[cfg(feature = "filter-blanket-impl")]
@@ -515,7 +968,7 @@ macro_rules! filter_repository_ext {
 ///
 /// impl FilterRepository<User> for UserRepository {
 ///     fn filter_query_builder<'args>() -> QueryBuilder<'args, Database> {
-///         QueryBuilder::new("SELECT * FROM users WHERE ")
+///         QueryBuilder::new("SELECT * FROM users")
 ///     }
 /// }
 ///
@@ -554,6 +1007,18 @@ macro_rules! filter_repository_ext {
 ///    - [`get_optional_by_any_filter`](FilterRepository::get_optional_by_any_filter) - Get a record if it exists
 ///    - [`get_optional_by_any_filter_with_executor`](FilterRepository::get_optional_by_any_filter_with_executor) - Same, but with a custom executor
 ///
+/// 4. Pages:
+///    - [`get_page_by_any_filter`](FilterRepository::get_page_by_any_filter) - Get a page of records, with offset or keyset pagination
+///    - [`get_page_by_any_filter_with_executor`](FilterRepository::get_page_by_any_filter_with_executor) - Same, but with a custom connection
+///
+/// 5. Counting:
+///    - [`count_by_any_filter`](FilterRepository::count_by_any_filter) - Count the matching records without fetching them
+///    - [`exists_by_any_filter`](FilterRepository::exists_by_any_filter) - Check if any record matches
+///
+/// 6. Streams:
+///    - [`stream_by_any_filter`](FilterRepository::stream_by_any_filter) - Stream the matching records without collecting them
+///    - [`stream_by_any_filter_with_executor`](FilterRepository::stream_by_any_filter_with_executor) - Same, but with a custom executor
+///
 /// # Implementation Notes
 ///
 /// 1. Required method: [`filter_query_builder`](FilterRepository::filter_query_builder) - Creates a query builder for filter-based queries
@@ -582,7 +1047,8 @@ where
     /// SELECT statement prefix. It forms the foundation for all filter-based queries
     /// in the repository.
     ///
-    /// NOTE: You must not include a WHERE clause in the query
+    /// NOTE: You must not include a WHERE clause in the query, nor a trailing space, as every
+    /// clause of the filter starts with one
     ///
     /// # Type Parameters
     ///
@@ -595,26 +1061,122 @@ where
 
     /// Builds the Query and applies the given filter only if the filter has defined that
     /// it should be applied, it will also append the start of the `WHERE` clause.
+    ///
+    /// The other [clauses](SqlFilter::apply_clauses) of the filter, like `GROUP BY`, follow the
+    /// `WHERE` clause, then [`post_filter_query`](FilterRepository::post_filter_query), then the
+    /// [`ordering`](SqlFilter::ordering) of the filter as an `ORDER BY` clause and its
+    /// [`row_limit`](SqlFilter::row_limit) and [`row_offset`](SqlFilter::row_offset) as `LIMIT`
+    /// and `OFFSET`, whether the filter was applied or not.
     #[inline]
     fn prepare_filter_query<'args>(filter: impl SqlFilter<'args>) -> QueryBuilder<'args, Database> {
         let mut builder = Self::filter_query_builder();
 
-        if filter.should_apply_filter() {
-            builder.push("WHERE ");
+        push_clauses_with(&mut builder, filter, true, Self::post_filter_query);
 
-            filter.apply_filter(&mut builder);
-        }
-
-        Self::post_filter_query(builder)
+        builder
     }
 
     /// If you need anything to be after the WHERE clause in the query you will need to override this
-    /// method to add it.
+    /// method to add it. It is called right after the `WHERE`, `GROUP BY` and `HAVING` clauses of
+    /// the filter and before its `ORDER BY`, `LIMIT` and `OFFSET` clauses, so an override adding a
+    /// `GROUP BY` or `ORDER BY` must not be used with filters that set the same clause.
     #[inline(always)]
     fn post_filter_query(builder: QueryBuilder<Database>) -> QueryBuilder<Database> {
         builder
     }
 
+    /// Builds the same query as [`prepare_filter_query`](FilterRepository::prepare_filter_query)
+    /// as a subquery, starting the builder with `prefix`. The caller closes the subquery.
+    ///
+    /// Only the SQL of [`filter_query_builder`](FilterRepository::filter_query_builder) is used,
+    /// so it must not bind any arguments itself. The [`ordering`](SqlFilter::ordering) of the
+    /// filter is left out as the order of a subquery is not kept, a page is ordered by its
+    /// [`PageRequest`] instead. It is only kept when the filter has a limit or an offset, to pick
+    /// the same rows as [`prepare_filter_query`](FilterRepository::prepare_filter_query).
+    #[inline]
+    fn prepare_filter_subquery<'args>(
+        prefix: &str,
+        filter: impl SqlFilter<'args>,
+    ) -> QueryBuilder<'args, Database> {
+        let mut builder = QueryBuilder::new(prefix);
+        builder.push(Self::filter_query_builder().sql());
+
+        let ordered = filter.row_limit().is_some() || filter.row_offset().is_some();
+        push_clauses_with(&mut builder, filter, ordered, Self::post_filter_query);
+
+        builder
+    }
+
+    /// Builds a query counting every record matching the filter.
+    ///
+    /// The select list of [`filter_query_builder`](FilterRepository::filter_query_builder) is
+    /// replaced with `COUNT(*)`, turning `SELECT * FROM users WHERE ...` into
+    /// `SELECT COUNT(*) FROM users WHERE ...`. Queries where that would change the result, like
+    /// `SELECT DISTINCT`, a filter that [groups the rows](SqlFilter::groups_rows) or has a limit
+    /// or offset, or a [`post_filter_query`](FilterRepository::post_filter_query) adding a
+    /// `GROUP BY`, are wrapped in `SELECT COUNT(*) FROM (...) AS total` instead.
+    #[inline]
+    fn prepare_count_query<'args>(
+        filter: impl SqlFilter<'args>,
+    ) -> crate::Result<QueryBuilder<'args, Database>> {
+        let select = Self::filter_query_builder();
+        let has_post_query = !Self::post_filter_query(QueryBuilder::new("")).sql().is_empty();
+        let changes_rows =
+            filter.groups_rows() || filter.row_limit().is_some() || filter.row_offset().is_some();
+
+        match replace_select_list(select.sql(), "COUNT(*)") {
+            Some(sql) if !has_post_query && !changes_rows => {
+                let mut builder = QueryBuilder::new(sql);
+                push_clauses(&mut builder, filter, false);
+
+                Ok(builder)
+            }
+            _ => {
+                let mut builder = Self::prepare_filter_subquery("SELECT COUNT(*) FROM (", filter);
+                builder.push(") AS total");
+
+                Ok(builder)
+            }
+        }
+    }
+
+    /// Builds a query checking if any record matches the filter, by wrapping the filter query in
+    /// `SELECT EXISTS(...)`.
+    ///
+    /// The select list of the filter query is replaced with `1` when that does not change which
+    /// rows are returned.
+    #[inline]
+    fn prepare_exists_query<'args>(
+        filter: impl SqlFilter<'args>,
+    ) -> crate::Result<QueryBuilder<'args, Database>> {
+        let select = Self::filter_query_builder();
+
+        let mut builder = QueryBuilder::new("SELECT EXISTS(");
+        builder.push(replace_select_list(select.sql(), "1").unwrap_or_else(|| select.into_sql()));
+        push_clauses_with(&mut builder, filter, false, Self::post_filter_query);
+        builder.push(")");
+
+        Ok(builder)
+    }
+
+    /// Builds a query selecting a single page of the records matching the filter, by wrapping the
+    /// filter query in `SELECT * FROM (...) AS page` followed by [`PageRequest::push_page`].
+    ///
+    /// The ordering and key columns of the request refer to the columns selected by the filter
+    /// query, so aliased columns are referred to by their alias.
+    #[inline]
+    fn prepare_page_query<'args>(
+        filter: impl SqlFilter<'args>,
+        request: &PageRequest,
+    ) -> crate::Result<QueryBuilder<'args, Database>> {
+        let mut builder = Self::prepare_filter_subquery("SELECT * FROM (", filter);
+        builder.push(") AS page");
+
+        request.push_page(&mut builder)?;
+
+        Ok(builder)
+    }
+
     cfg_if! {
         if #[cfg(all(feature = "filter_debug_impl", feature = "log_err"))] {
             filter_repository_methods! {
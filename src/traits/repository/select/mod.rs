@@ -8,8 +8,11 @@ use crate::mod_def;
 use crate::prelude::Database;
 use crate::traits::{Model, Repository};
 use crate::types::QueryAs;
-use crate::utils::tracing_debug_log;
-use sqlx::{Database as DatabaseTrait, Executor, FromRow};
+use crate::utils::{
+    exists_from_row, instrument_rows, replace_select_list, tracing_debug_log,
+};
+use futures::Stream;
+use sqlx::{Database as DatabaseTrait, Execute, Executor, FromRow};
 
 /// Trait for repositories that can retrieve records from the database.
 ///
@@ -35,8 +38,12 @@ use sqlx::{Database as DatabaseTrait, Executor, FromRow};
 ///
 /// * [`get_all_with_executor`](SelectRepository::get_all_with_executor) - Execute the get_all query with a custom executor
 /// * [`get_all`](SelectRepository::get_all) - Retrieve all records using the repository's pool
+/// * [`stream_all_with_executor`](SelectRepository::stream_all_with_executor) - Stream the get_all query with a custom executor
+/// * [`stream_all`](SelectRepository::stream_all) - Stream all records using the repository's pool
 /// * [`get_by_id_with_executor`](SelectRepository::get_by_id_with_executor) - Execute the get_by_id query with a custom executor
 /// * [`get_by_id`](SelectRepository::get_by_id) - Retrieve a record by ID using the repository's pool
+/// * [`count_all`](SelectRepository::count_all) - Count the records of the get_all query
+/// * [`exists_by_id`](SelectRepository::exists_by_id) - Check if a record with the ID exists
 ///
 /// # Examples
 ///
@@ -85,8 +92,8 @@ use sqlx::{Database as DatabaseTrait, Executor, FromRow};
 ///    - [`get_all_query`](SelectRepository::get_all_query)
 ///    - [`get_by_id_query`](SelectRepository::get_by_id_query)
 /// 2. The execution methods are provided automatically based on these query methods
-/// 3. Consider implementing pagination for [`get_all`](SelectRepository::get_all) if the table may contain a large
-///    number of records
+/// 3. Consider implementing pagination for [`get_all`](SelectRepository::get_all) or using
+///    [`stream_all`](SelectRepository::stream_all) if the table may contain a large number of records
 /// 4. Use parameter binding to prevent SQL injection
 /// 5. Consider caching strategies for frequently accessed data
 /// 6. The trait supports using custom executors (like transactions) via the `_with_executor` methods
@@ -128,7 +135,7 @@ where
     ///     sqlx::query_as!(User, "SELECT * FROM users")
     /// }
     /// ```
-    fn get_all_query(&self) -> QueryAs<M>;
+    fn get_all_query(&self) -> QueryAs<'_, M>;
 
     /// Creates a query to retrieve a single model instance by its ID.
     ///
@@ -159,7 +166,7 @@ where
     ///     sqlx::query_as!(User, "SELECT * FROM users WHERE id = $1", id)
     /// }
     /// ```
-    fn get_by_id_query(&self, id: impl Into<M::Id>) -> QueryAs<M>;
+    fn get_by_id_query(&self, id: impl Into<M::Id>) -> QueryAs<'_, M>;
 
     tracing_debug_log! {
         [skip_all, Self::repository_span(), "get_all",]
@@ -224,6 +231,64 @@ where
         self.get_all_with_executor(self.pool()).await
     }
 
+    /// Streams the records of the `get_all` query with a custom executor.
+    ///
+    /// Unlike [`get_all_with_executor`](SelectRepository::get_all_with_executor) the records are
+    /// decoded one at a time as the database returns them, so only a single record is held in
+    /// memory. The query runs in the same `get_all` span.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The executor type, such as a transactions or connection pool
+    ///
+    /// # Parameters
+    ///
+    /// * `tx` - The executor to use for the query
+    ///
+    /// # Returns
+    ///
+    /// * A [`Stream`] of every model, or the error that ended the query
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut users = pin!(repo.stream_all_with_executor(&mut *tx));
+    ///
+    /// while let Some(user) = users.try_next().await? {
+    ///     export(user)?;
+    /// }
+    /// ```
+    #[inline]
+    fn stream_all_with_executor<'a, 'c, E>(
+        &'a self,
+        tx: E,
+    ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+    where
+        M: 'a,
+        E: Executor<'c, Database = Database> + 'a,
+        'c: 'a,
+    {
+        let span = tracing::debug_span!(parent: &Self::repository_span(), "get_all");
+
+        instrument_rows(span, self.get_all_query().fetch(tx))
+    }
+
+    /// Streams all records of this model type from the database.
+    ///
+    /// This method is automatically provided and simply calls [`stream_all_with_executor`](SelectRepository::stream_all_with_executor)
+    /// with the repository's connection pool.
+    ///
+    /// # Returns
+    ///
+    /// * A [`Stream`] of every model, or the error that ended the query
+    #[inline]
+    fn stream_all<'a>(&'a self) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+    where
+        M: 'a,
+    {
+        self.stream_all_with_executor(self.pool())
+    }
+
     tracing_debug_log! {
         [skip_all, Self::repository_span(), "get_by_id",]
         /// Executes the `get_by_id` query with a custom executor.
@@ -285,4 +350,126 @@ where
     async fn get_by_id(&self, id: impl Into<M::Id>) -> crate::Result<Option<M>> {
         self.get_by_id_with_executor(self.pool(), id).await
     }
+
+    tracing_debug_log! {
+        [skip_all, Self::repository_span(), "count_all",]
+        /// Counts the records of the `get_all` query with a custom executor.
+        ///
+        /// The select list of [`get_all_query`](SelectRepository::get_all_query) is replaced with
+        /// `COUNT(*)`, or the query is wrapped in `SELECT COUNT(*) FROM (...)` if that would change
+        /// the result, keeping any bound arguments.
+        ///
+        /// # Type Parameters
+        ///
+        /// * `E` - The executor type, such as a transactions or connection pool
+        ///
+        /// # Parameters
+        ///
+        /// * `tx` - The executor to use for the query
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<u64>`] - A Result containing the number of records
+        #[inline(always)]
+        async fn count_all_with_executor<'c, E>(
+            &self,
+            tx: E,
+        ) -> crate::Result<u64>
+        where
+            E: Executor<'c, Database = Database>,
+        {
+            let mut query = self.get_all_query();
+
+            let sql = replace_select_list(query.sql(), "COUNT(*)").unwrap_or_else(|| {
+                format!(
+                    "SELECT COUNT(*) FROM ({}) AS total",
+                    query.sql().trim_end().trim_end_matches(';')
+                )
+            });
+            let arguments = query
+                .take_arguments()
+                .map_err(|err| crate::Error::Boxed(err))?
+                .unwrap_or_default();
+
+            let count: i64 = sqlx::query_scalar_with(&sql, arguments).fetch_one(tx).await?;
+
+            Ok(count as u64)
+        }
+    }
+
+    /// Counts the records of this model type in the database.
+    ///
+    /// This method is automatically provided and simply calls [`count_all_with_executor`](SelectRepository::count_all_with_executor)
+    /// with the repository's connection pool.
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<u64>`] - A Result containing the number of records
+    #[inline(always)]
+    async fn count_all(&self) -> crate::Result<u64> {
+        self.count_all_with_executor(self.pool()).await
+    }
+
+    tracing_debug_log! {
+        [skip_all, Self::repository_span(), "exists_by_id",]
+        /// Checks if a record with the given ID exists with a custom executor.
+        ///
+        /// The query from [`get_by_id_query`](SelectRepository::get_by_id_query) is wrapped in
+        /// `SELECT EXISTS(...)`, so the record itself is never fetched.
+        ///
+        /// # Type Parameters
+        ///
+        /// * `E` - The executor type, such as a transactions or connection pool
+        ///
+        /// # Parameters
+        ///
+        /// * `tx` - The executor to use for the query
+        /// * `id` - Any value that can be converted into the model's ID type
+        ///
+        /// # Returns
+        ///
+        /// * [`crate::Result<bool>`] - A Result containing `true` if the record exists
+        #[inline(always)]
+        async fn exists_by_id_with_executor<'c, E>(
+            &self,
+            tx: E,
+            id: impl Into<M::Id>
+        ) -> crate::Result<bool>
+        where
+            E: Executor<'c, Database = Database>,
+        {
+            let mut query = self.get_by_id_query(id);
+
+            let select = query.sql().trim_end().trim_end_matches(';');
+            let sql = format!(
+                "SELECT EXISTS({})",
+                replace_select_list(select, "1").as_deref().unwrap_or(select)
+            );
+            let arguments = query
+                .take_arguments()
+                .map_err(|err| crate::Error::Boxed(err))?
+                .unwrap_or_default();
+
+            let row = sqlx::query_with(&sql, arguments).fetch_one(tx).await?;
+
+            exists_from_row(&row)
+        }
+    }
+
+    /// Checks if a record with the given ID exists.
+    ///
+    /// This method is automatically provided and simply calls [`exists_by_id_with_executor`](SelectRepository::exists_by_id_with_executor)
+    /// with the repository's connection pool.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Any value that can be converted into the model's ID type
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<bool>`] - A Result containing `true` if the record exists
+    #[inline(always)]
+    async fn exists_by_id(&self, id: impl Into<M::Id>) -> crate::Result<bool> {
+        self.exists_by_id_with_executor(self.pool(), id).await
+    }
 }
@@ -0,0 +1,408 @@
+//! Repositories bound to a transaction, see
+//! [`in_tx`](crate::traits::TransactionRepository::in_tx).
+
+use crate::traits::{
+    DeleteRepository, FilterRepository, FilterRepositoryExt, InsertableRepository, Model,
+    SaveRepository, SelectRepository, SqlFilter, Table, UpdatableRepository,
+};
+use crate::types::{Database, Page, PageRequest};
+use crate::utils::tracing_debug_log;
+use futures::Stream;
+use sqlx::{Database as DatabaseTrait, FromRow, Transaction};
+#[cfg(feature = "filter_debug_impl")]
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// A repository bound to a transaction.
+///
+/// The view has the same methods as the repository traits, like
+/// [`insert`](InsertableRepository::insert) or [`get_all_by_filter`](FilterRepositoryExt::get_all_by_filter),
+/// but runs them with the transaction instead of the pool of the repository. Code written
+/// against a repository reads the same when it runs in a transaction:
+///
+/// ```no_compile
+/// let mut tx = USER_REPO.pool().begin().await?;
+/// let mut users = USER_REPO.in_tx(&mut tx);
+///
+/// let user = users.insert_returning(user).await?;
+/// let total = users.count_by_filter(UserFilter::new().name("Alice")).await?;
+///
+/// tx.commit().await?;
+/// ```
+///
+/// Only methods running a single query are available, methods splitting their work into batches
+/// like [`insert_many`](InsertableRepository::insert_many) run their batches concurrently on the
+/// pool and can not share a transaction. Any other `_with_executor` method can be given
+/// [`transaction`](InTransaction::transaction).
+pub struct InTransaction<'r, 't, 'c, R, M> {
+    repo: &'r R,
+    tx: &'t mut Transaction<'c, Database>,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<'r, 't, 'c, R, M> InTransaction<'r, 't, 'c, R, M> {
+    #[inline]
+    pub(crate) fn new(repo: &'r R, tx: &'t mut Transaction<'c, Database>) -> Self {
+        Self {
+            repo,
+            tx,
+            model: PhantomData,
+        }
+    }
+
+    /// Gets the repository.
+    #[inline]
+    pub fn repository(&self) -> &'r R {
+        self.repo
+    }
+
+    /// Gets the transaction, for methods of the repository not available on the view.
+    #[inline]
+    pub fn transaction(&mut self) -> &mut Transaction<'c, Database> {
+        self.tx
+    }
+}
+
+impl<R, M> InTransaction<'_, '_, '_, R, M>
+where
+    R: InsertableRepository<M>,
+    M: Model,
+{
+    /// Inserts a model, see [`InsertableRepository::insert`].
+    #[inline]
+    pub async fn insert(&mut self, model: M) -> crate::Result<M> {
+        self.repo.insert_with_executor(&mut **self.tx, model).await
+    }
+
+    /// Inserts a model by reference, see [`InsertableRepository::insert_ref`].
+    #[inline]
+    pub async fn insert_ref(&mut self, model: &M) -> crate::Result<()> {
+        self.repo.insert_ref_with_executor(&mut **self.tx, model).await
+    }
+
+    /// Inserts a model and returns it as stored, see [`InsertableRepository::insert_returning`].
+    #[inline]
+    pub async fn insert_returning(&mut self, model: M) -> crate::Result<M>
+    where
+        M: Table + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Unpin,
+    {
+        self.repo
+            .insert_returning_with_executor(&mut *self.tx, model)
+            .await
+    }
+
+    /// Inserts models with multi-row statements, see [`InsertableRepository::insert_bulk`].
+    pub async fn insert_bulk<I>(&mut self, models: I) -> crate::Result<()>
+    where
+        M: Table,
+        I: IntoIterator<Item = M>,
+    {
+        let models = models.into_iter().collect::<Vec<_>>();
+
+        self.repo
+            .insert_bulk_with_executor(&mut *self.tx, &models)
+            .await
+    }
+}
+
+impl<R, M> InTransaction<'_, '_, '_, R, M>
+where
+    R: UpdatableRepository<M>,
+    M: Model,
+{
+    /// Updates a model, see [`UpdatableRepository::update`].
+    #[inline]
+    pub async fn update(&mut self, model: M) -> crate::Result<M> {
+        self.repo.update_with_executor(&mut **self.tx, model).await
+    }
+
+    /// Updates a model by reference, see [`UpdatableRepository::update_ref`].
+    #[inline]
+    pub async fn update_ref(&mut self, model: &M) -> crate::Result<()> {
+        self.repo.update_ref_with_executor(&mut **self.tx, model).await
+    }
+}
+
+impl<R, M> InTransaction<'_, '_, '_, R, M>
+where
+    R: SaveRepository<M>,
+    M: Model,
+{
+    /// Inserts or updates a model, see [`SaveRepository::save`].
+    #[inline]
+    pub async fn save(&mut self, model: M) -> crate::Result<M> {
+        self.repo.save_with_executor(&mut **self.tx, model).await
+    }
+
+    /// Inserts or updates a model by reference, see [`SaveRepository::save_ref`].
+    #[inline]
+    pub async fn save_ref(&mut self, model: &M) -> crate::Result<()> {
+        self.repo.save_ref_with_executor(&mut **self.tx, model).await
+    }
+}
+
+impl<R, M> InTransaction<'_, '_, '_, R, M>
+where
+    R: DeleteRepository<M>,
+    M: Model,
+{
+    /// Deletes a record by its id, see [`DeleteRepository::delete_by_id`].
+    #[inline]
+    pub async fn delete_by_id(&mut self, id: impl Into<M::Id> + Send) -> crate::Result<()> {
+        self.repo
+            .delete_by_id_with_executor(&mut **self.tx, id)
+            .await
+    }
+
+    /// Deletes the records matching the filter, see [`DeleteRepository::delete_by_filter`].
+    #[inline]
+    pub async fn delete_by_filter(
+        &mut self,
+        filter: impl SqlFilter<'_> + Send,
+    ) -> crate::Result<()> {
+        self.repo
+            .delete_by_filter_with_executor(&mut **self.tx, filter)
+            .await
+    }
+}
+
+impl<R, M> InTransaction<'_, '_, '_, R, M>
+where
+    R: SelectRepository<M>,
+    M: Model + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Send + Unpin,
+{
+    // `get_all_with_executor` and `get_by_id_with_executor` take executors valid for any lifetime,
+    // which a borrowed transaction is not, so their queries are run here in the same span.
+    tracing_debug_log! {
+        [skip_all, R::repository_span(), "get_all",]
+        /// Retrieves all records, see [`SelectRepository::get_all`].
+        #[inline]
+        pub async fn get_all(&mut self) -> crate::Result<Vec<M>> {
+            self.repo
+                .get_all_query()
+                .fetch_all(&mut **self.tx)
+                .await
+                .map_err(Into::into)
+        }
+    }
+
+    /// Streams all records, see [`SelectRepository::stream_all`].
+    #[inline]
+    pub fn stream_all(&mut self) -> impl Stream<Item = crate::Result<M>> + Send + '_
+    where
+        R: Sync,
+    {
+        self.repo.stream_all_with_executor(&mut **self.tx)
+    }
+
+    tracing_debug_log! {
+        [skip_all, R::repository_span(), "get_by_id",]
+        /// Retrieves a record by its id, see [`SelectRepository::get_by_id`].
+        #[inline]
+        pub async fn get_by_id(&mut self, id: impl Into<M::Id>) -> crate::Result<Option<M>> {
+            self.repo
+                .get_by_id_query(id)
+                .fetch_optional(&mut **self.tx)
+                .await
+                .map_err(Into::into)
+        }
+    }
+
+    /// Counts all records, see [`SelectRepository::count_all`].
+    #[inline]
+    pub async fn count_all(&mut self) -> crate::Result<u64> {
+        self.repo.count_all_with_executor(&mut **self.tx).await
+    }
+
+    /// Checks if a record with the id exists, see [`SelectRepository::exists_by_id`].
+    #[inline]
+    pub async fn exists_by_id(&mut self, id: impl Into<M::Id>) -> crate::Result<bool> {
+        self.repo.exists_by_id_with_executor(&mut **self.tx, id).await
+    }
+}
+
+macro_rules! in_transaction_filter_methods {
+    ($($debug:ident)?) => {
+        impl<R, M> InTransaction<'_, '_, '_, R, M>
+        where
+            R: FilterRepository<M>,
+            M: Model + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row> + Send + Unpin,
+        {
+            /// Retrieves all records matching the filter, see
+            /// [`FilterRepository::get_all_by_any_filter`].
+            #[inline]
+            pub async fn get_all_by_any_filter<F>(&mut self, filter: F) -> crate::Result<Vec<M>>
+            where
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.get_all_by_any_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Retrieves exactly one record matching the filter, see
+            /// [`FilterRepository::get_one_by_any_filter`].
+            #[inline]
+            pub async fn get_one_by_any_filter<F>(&mut self, filter: F) -> crate::Result<M>
+            where
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.get_one_by_any_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Retrieves the record matching the filter if there is one, see
+            /// [`FilterRepository::get_optional_by_any_filter`].
+            #[inline]
+            pub async fn get_optional_by_any_filter<F>(
+                &mut self,
+                filter: F,
+            ) -> crate::Result<Option<M>>
+            where
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.get_optional_by_any_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Retrieves a single page of the records matching the filter, see
+            /// [`FilterRepository::get_page_by_any_filter`].
+            #[inline]
+            pub async fn get_page_by_any_filter<F>(
+                &mut self,
+                filter: F,
+                request: PageRequest,
+            ) -> crate::Result<Page<M>>
+            where
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Clone + Send,
+            {
+                self.repo
+                    .get_page_by_any_filter_with_executor(&mut *self.tx, filter, request)
+                    .await
+            }
+
+            /// Counts the records matching the filter, see
+            /// [`FilterRepository::count_by_any_filter`].
+            #[inline]
+            pub async fn count_by_any_filter<F>(&mut self, filter: F) -> crate::Result<u64>
+            where
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.count_by_any_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Checks if any record matches the filter, see
+            /// [`FilterRepository::exists_by_any_filter`].
+            #[inline]
+            pub async fn exists_by_any_filter<F>(&mut self, filter: F) -> crate::Result<bool>
+            where
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.exists_by_any_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Streams the records matching the filter, see
+            /// [`FilterRepository::stream_by_any_filter`].
+            #[inline]
+            pub fn stream_by_any_filter<'a, F>(
+                &'a mut self,
+                filter: F,
+            ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+            where
+                M: 'a,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
+            {
+                self.repo.stream_by_any_filter_with_executor(&mut **self.tx, filter)
+            }
+
+            /// Retrieves all records matching the filter, see
+            /// [`FilterRepositoryExt::get_all_by_filter`].
+            #[inline]
+            pub async fn get_all_by_filter<F>(&mut self, filter: F) -> crate::Result<Vec<M>>
+            where
+                R: FilterRepositoryExt<M, F>,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.get_all_by_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Retrieves exactly one record matching the filter, see
+            /// [`FilterRepositoryExt::get_one_by_filter`].
+            #[inline]
+            pub async fn get_one_by_filter<F>(&mut self, filter: F) -> crate::Result<M>
+            where
+                R: FilterRepositoryExt<M, F>,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.get_one_by_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Retrieves the record matching the filter if there is one, see
+            /// [`FilterRepositoryExt::get_optional_by_filter`].
+            #[inline]
+            pub async fn get_optional_by_filter<F>(&mut self, filter: F) -> crate::Result<Option<M>>
+            where
+                R: FilterRepositoryExt<M, F>,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.get_optional_by_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Retrieves a single page of the records matching the filter, see
+            /// [`FilterRepositoryExt::get_page_by_filter`].
+            #[inline]
+            pub async fn get_page_by_filter<F>(
+                &mut self,
+                filter: F,
+                request: PageRequest,
+            ) -> crate::Result<Page<M>>
+            where
+                R: FilterRepositoryExt<M, F>,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Clone + Send,
+            {
+                self.repo
+                    .get_page_by_filter_with_executor(&mut *self.tx, filter, request)
+                    .await
+            }
+
+            /// Counts the records matching the filter, see
+            /// [`FilterRepositoryExt::count_by_filter`].
+            #[inline]
+            pub async fn count_by_filter<F>(&mut self, filter: F) -> crate::Result<u64>
+            where
+                R: FilterRepositoryExt<M, F>,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.count_by_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Checks if any record matches the filter, see
+            /// [`FilterRepositoryExt::exists_by_filter`].
+            #[inline]
+            pub async fn exists_by_filter<F>(&mut self, filter: F) -> crate::Result<bool>
+            where
+                R: FilterRepositoryExt<M, F>,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send,
+            {
+                self.repo.exists_by_filter_with_executor(&mut **self.tx, filter).await
+            }
+
+            /// Streams the records matching the filter, see
+            /// [`FilterRepositoryExt::stream_by_filter`].
+            #[inline]
+            pub fn stream_by_filter<'a, F>(
+                &'a mut self,
+                filter: F,
+            ) -> impl Stream<Item = crate::Result<M>> + Send + 'a
+            where
+                R: FilterRepositoryExt<M, F>,
+                M: 'a,
+                F: for<'b> SqlFilter<'b, Database> $(+ $debug)? + Send + 'a,
+            {
+                self.repo.stream_by_filter_with_executor(&mut **self.tx, filter)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "filter_debug_impl")]
+in_transaction_filter_methods! {Debug}
+
+#[cfg(not(feature = "filter_debug_impl"))]
+in_transaction_filter_methods! {}
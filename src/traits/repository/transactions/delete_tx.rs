@@ -1,5 +1,7 @@
 use crate::filter::InValues;
 use crate::prelude::*;
+use sqlx::Acquire;
+use std::fmt::Debug;
 use std::future::Future;
 
 pub trait DeleteRepositoryTransaction<M: Model>:
@@ -12,7 +14,21 @@ pub trait DeleteRepositoryTransaction<M: Model>:
     where
         M::Id: 'a,
     {
-        self.with_transaction(move |mut tx| async move {
+        self.delete_by_id_in_transaction_on(self.pool(), id)
+    }
+
+    /// Deletes a model by its id in a transactions started on `conn`, which is a savepoint when
+    /// `conn` is an existing transactions, see [`TransactionRepository::with_transaction_on`].
+    fn delete_by_id_in_transaction_on<'a, 'c, A>(
+        &'a self,
+        conn: A,
+        id: impl Into<M::Id> + Send + 'a,
+    ) -> impl Future<Output = Result<(), Error>> + Send + 'a
+    where
+        A: Acquire<'c, Database = Database> + Send + 'a,
+        M::Id: 'a,
+    {
+        self.with_transaction_on(conn, move |mut tx| async move {
             let res = self.delete_by_id_with_executor(&mut *tx, id).await;
 
             (res, tx)
@@ -23,7 +39,21 @@ pub trait DeleteRepositoryTransaction<M: Model>:
         &'a self,
         filter: impl SqlFilter<'a> + Send + 'a,
     ) -> impl Future<Output = Result<(), Error>> + Send + 'a {
-        self.with_transaction(move |mut tx| async move {
+        self.delete_by_filter_in_transaction_on(self.pool(), filter)
+    }
+
+    /// Deletes all models matching `filter` in a transactions started on `conn`, which is a
+    /// savepoint when `conn` is an existing transactions, see
+    /// [`TransactionRepository::with_transaction_on`].
+    fn delete_by_filter_in_transaction_on<'a, 'c, A>(
+        &'a self,
+        conn: A,
+        filter: impl SqlFilter<'a> + Send + 'a,
+    ) -> impl Future<Output = Result<(), Error>> + Send + 'a
+    where
+        A: Acquire<'c, Database = Database> + Send + 'a,
+    {
+        self.with_transaction_on(conn, move |mut tx| async move {
             let res = self.delete_by_filter_with_executor(&mut *tx, filter).await;
 
             (res, tx)
@@ -36,13 +66,31 @@ pub trait DeleteRepositoryTransaction<M: Model>:
         values: I,
     ) -> impl Future<Output = Result<(), Error>> + Send + 'a
     where
-        M::Id: ::sqlx::Type<Database> + ::sqlx::Encode<'a, Database> + 'a,
+        M::Id: ::sqlx::Type<Database> + ::sqlx::Encode<'a, Database> + Debug + 'a,
+        I: IntoIterator<Item = M::Id> + Send + 'a,
+        I::IntoIter: Send + 'a,
+    {
+        self.delete_by_values_in_transaction_on(self.pool(), column, values)
+    }
+
+    /// Deletes all models where `column` is one of `values` in a transactions started on `conn`,
+    /// which is a savepoint when `conn` is an existing transactions, see
+    /// [`TransactionRepository::with_transaction_on`].
+    fn delete_by_values_in_transaction_on<'a, 'c, A, I>(
+        &'a self,
+        conn: A,
+        column: &'static str,
+        values: I,
+    ) -> impl Future<Output = Result<(), Error>> + Send + 'a
+    where
+        A: Acquire<'c, Database = Database> + Send + 'a,
+        M::Id: ::sqlx::Type<Database> + ::sqlx::Encode<'a, Database> + Debug + 'a,
         I: IntoIterator<Item = M::Id> + Send + 'a,
         I::IntoIter: Send + 'a,
     {
         let filter = InValues::new(column, values);
 
-        self.delete_by_filter_in_transaction(filter)
+        self.delete_by_filter_in_transaction_on(conn, filter)
     }
 }
 
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use sqlx::Acquire;
 use std::future::Future;
 
 pub trait InsertableRepositoryTransaction<M: Model>:
@@ -11,10 +12,31 @@ pub trait InsertableRepositoryTransaction<M: Model>:
     where
         M: 'a,
     {
-        self.with_transaction(move |mut tx| async move {
+        self.insert_in_transaction_on(self.pool(), model)
+    }
+
+    /// Inserts a model in a transactions started on `conn`, which is a savepoint when `conn` is
+    /// an existing transactions, see [`TransactionRepository::with_transaction_on`].
+    fn insert_in_transaction_on<'a, 'c, A>(
+        &'a self,
+        conn: A,
+        model: M,
+    ) -> impl Future<Output = Result<M, Error>> + Send + 'a
+    where
+        A: Acquire<'c, Database = Database> + Send + 'a,
+        M: 'a,
+    {
+        self.with_transaction_on(conn, move |mut tx| async move {
             let res = self.insert_with_executor(&mut *tx, model).await;
 
             (res, tx)
         })
     }
 }
+
+impl<T, M> InsertableRepositoryTransaction<M> for T
+where
+    T: InsertableRepository<M> + TransactionRepository<M>,
+    M: Model,
+{
+}
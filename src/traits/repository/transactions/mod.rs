@@ -5,14 +5,15 @@
 //! implemented using [`async_trait`], if this changes in the future then I will fix this
 
 use crate::{
+    error::RetryableError,
     mod_def,
     traits::{Model, Repository},
-    types::Database,
+    types::{Database, RetryPolicy, TransactionOptions},
 };
 use futures::future::try_join_all;
-use sqlx::{Error, Transaction};
+use sqlx::{Acquire, Error, Transaction};
 use std::future::Future;
-use std::sync::Arc;
+use tracing::Instrument;
 
 mod_def! {
     !export
@@ -20,6 +21,8 @@ mod_def! {
     pub(crate) mod update_tx;
     pub(crate) mod delete_tx;
     pub(crate) mod save_tx;
+    pub(crate) mod shared;
+    pub(crate) mod bound;
 }
 
 /// Extension trait for Repository to work with transactions
@@ -35,6 +38,32 @@ pub trait TransactionRepository<M>: Repository<M>
 where
     M: Model,
 {
+    /// Binds the repository to `tx`, returning a view with the same methods as the repository
+    /// traits that runs them with the transactions instead of the pool.
+    ///
+    /// See [`InTransaction`] for the available methods.
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// let mut tx = repo.pool().begin().await?;
+    ///
+    /// let user = repo.in_tx(&mut tx).save(user).await?;
+    /// repo.in_tx(&mut tx).delete_by_id(old_id).await?;
+    ///
+    /// tx.commit().await?;
+    /// ```
+    #[inline]
+    fn in_tx<'r, 't, 'c>(
+        &'r self,
+        tx: &'t mut Transaction<'c, Database>,
+    ) -> InTransaction<'r, 't, 'c, Self, M>
+    where
+        Self: Sized,
+    {
+        InTransaction::new(self, tx)
+    }
+
     /// Executes a callback within a transactions, handling the transactions lifecycle automatically.
     ///
     /// This method:
@@ -80,23 +109,269 @@ where
         Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
         R: Send + 'a,
         E: From<Error> + Send,
+    {
+        self.with_transaction_on(self.pool(), callback)
+    }
+
+    /// Executes a callback within a transactions started on `conn`, handling the transactions
+    /// lifecycle like [`with_transaction`](TransactionRepository::with_transaction).
+    ///
+    /// When `conn` is a pool or a plain connection a new transactions is started. When it is an
+    /// existing [`Transaction`] a savepoint is created instead with `SAVEPOINT`, which is
+    /// released with `RELEASE SAVEPOINT` if the result is `Ok` or rolled back with
+    /// `ROLLBACK TO SAVEPOINT` if it's `Err`. Only the work done in the callback is undone and the
+    /// outer transactions can carry on, this allows helpers that want transactional semantics
+    /// to be called both on their own and from inside a transactions.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A`: The connection type, must implement [`Acquire`]
+    /// * `F`: The type of the callback function [^func]
+    /// * `Fut`: The future type returned by the callback
+    /// * `R`: The result type
+    /// * `E`: The error type, which must be convertible from [`Error`]
+    ///
+    /// # Parameters
+    ///
+    /// * `conn`: The pool, connection or transactions to start the transactions on
+    /// * `callback`: A function that accepts a [`Transaction`] and returns a future
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to `Result<R, E>`.
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// let mut tx = repo.pool().begin().await?;
+    ///
+    /// let result = repo.with_transaction_on(&mut tx, |mut savepoint| async move {
+    ///     let res = repo.save_with_executor(&mut *savepoint, model).await;
+    ///     (res, savepoint)
+    /// }).await;
+    ///
+    /// tx.commit().await?;
+    /// ```
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn with_transaction_on<'a, 'b, A, F, Fut, R, E>(
+        &'a self,
+        conn: A,
+        callback: F,
+    ) -> impl Future<Output = Result<R, E>> + Send + 'a
+    where
+        A: Acquire<'b, Database = Database> + Send + 'a,
+        F: FnOnce(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + Send,
     {
         async move {
-            let transaction = self.pool().begin().await.map_err(E::from)?;
+            let transaction = conn.begin().await.map_err(E::from)?;
 
-            let (ret, tx) = callback(transaction).await;
+            run_transaction(transaction, callback).await
+        }
+    }
 
-            match ret {
-                Ok(val) => {
-                    tx.commit().await.map_err(E::from)?;
-                    Ok(val)
-                }
-                Err(err) => {
-                    tx.rollback().await.map_err(E::from)?;
-                    Err(err)
+    /// Executes a callback within a transactions started with `options`, handling the
+    /// transactions lifecycle like [`with_transaction`](TransactionRepository::with_transaction).
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: How the transactions is started, like its isolation level
+    /// * `callback`: A function that accepts a [`Transaction`] and returns a future [^func]
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to `Result<R, E>`.
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// let options = TransactionOptions::new()
+    ///     .isolation_level(IsolationLevel::RepeatableRead)
+    ///     .read_only(true);
+    ///
+    /// let report = repo.with_transaction_opts(options, |mut tx| async move {
+    ///     let res = repo.get_all_with_executor(&mut *tx).await;
+    ///     (res, tx)
+    /// }).await;
+    /// ```
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn with_transaction_opts<'a, 'b, F, Fut, R, E>(
+        &'a self,
+        options: TransactionOptions,
+        callback: F,
+    ) -> impl Future<Output = Result<R, E>> + Send + 'a
+    where
+        F: FnOnce(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + Send,
+    {
+        async move {
+            let transaction = options.begin(self.pool()).await.map_err(E::from)?;
+
+            run_transaction(transaction, callback).await
+        }
+    }
+
+    /// Executes a callback within a transactions, running it again in a new transactions while
+    /// it fails with a [retryable](RetryableError) error like a serialization failure or a
+    /// deadlock.
+    ///
+    /// Every attempt is handled like [`with_transaction`](TransactionRepository::with_transaction),
+    /// failed attempts are rolled back before waiting as long as `policy` says. The error of the
+    /// last attempt is returned once `policy` allows no more attempts. The number of attempts is
+    /// recorded in the `attempts` field of the `retrying_transaction` span.
+    ///
+    /// As the callback may be called more than once it must be [`FnMut`], anything it moves into
+    /// the future has to be cloned first.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy`: How often the transactions is run and how long to wait in between
+    /// * `callback`: A function that accepts a [`Transaction`] and returns a future [^func]
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to `Result<R, E>`.
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// let result = repo.with_retrying_transaction(RetryPolicy::new(5), |mut tx| {
+    ///     let model = model.clone();
+    ///
+    ///     async move {
+    ///         let res = repo.save_with_executor(&mut *tx, model).await;
+    ///         (res, tx)
+    ///     }
+    /// }).await;
+    /// ```
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn with_retrying_transaction<'a, 'b, F, Fut, R, E>(
+        &'a self,
+        policy: RetryPolicy,
+        callback: F,
+    ) -> impl Future<Output = Result<R, E>> + Send + 'a
+    where
+        F: FnMut(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + RetryableError + Send,
+    {
+        self.with_retrying_transaction_opts(TransactionOptions::new(), policy, callback)
+    }
+
+    /// Executes a callback within transactions started with `options`, retrying it like
+    /// [`with_retrying_transaction`](TransactionRepository::with_retrying_transaction).
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: How every transactions is started, like its isolation level
+    /// * `policy`: How often the transactions is run and how long to wait in between
+    /// * `callback`: A function that accepts a [`Transaction`] and returns a future [^func]
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn with_retrying_transaction_opts<'a, 'b, F, Fut, R, E>(
+        &'a self,
+        options: TransactionOptions,
+        policy: RetryPolicy,
+        mut callback: F,
+    ) -> impl Future<Output = Result<R, E>> + Send + 'a
+    where
+        F: FnMut(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + RetryableError + Send,
+    {
+        let span = tracing::debug_span!(
+            parent: &Self::repository_span(),
+            "retrying_transaction",
+            attempts = tracing::field::Empty,
+        );
+
+        async move {
+            let mut attempt = 1;
+
+            loop {
+                tracing::Span::current().record("attempts", attempt);
+
+                let result = match options.begin(self.pool()).await {
+                    Ok(transaction) => run_transaction(transaction, &mut callback).await,
+                    Err(err) => Err(E::from(err)),
+                };
+
+                match result {
+                    Err(err) if err.is_retryable() && attempt < policy.max_attempts => {
+                        let delay = policy.delay(attempt);
+                        tracing::debug!(attempt, ?delay, "Transaction failed, retrying");
+
+                        (policy.sleep)(delay).await;
+                        attempt += 1;
+                    }
+                    result => return result,
                 }
             }
         }
+        .instrument(span)
+    }
+
+    /// Executes a callback within a savepoint of `tx`, releasing the savepoint if the result is
+    /// `Ok` or rolling back to it if it's `Err`.
+    ///
+    /// This is a shorthand for [`with_transaction_on`](TransactionRepository::with_transaction_on)
+    /// with an existing transactions, the outer transactions is left open either way so its
+    /// owner decides whether to commit it.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx`: The transactions to create the savepoint in
+    /// * `callback`: A function that accepts the savepoint as a [`Transaction`] and returns a future [^func]
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to `Result<R, E>`.
+    ///
+    /// # Example
+    ///
+    /// ```no_compile
+    /// repo.with_transaction(|mut tx| async move {
+    ///     let res = repo.save_with_executor(&mut *tx, order).await;
+    ///
+    ///     // A failed audit entry is undone without losing the order
+    ///     let _ = repo.with_savepoint(&mut tx, |mut savepoint| async move {
+    ///         let res = audit_repo.insert_with_executor(&mut *savepoint, entry).await;
+    ///         (res, savepoint)
+    ///     }).await;
+    ///
+    ///     (res, tx)
+    /// }).await;
+    /// ```
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn with_savepoint<'a, 'b, 'c, F, Fut, R, E>(
+        &'a self,
+        tx: &'b mut Transaction<'c, Database>,
+        callback: F,
+    ) -> impl Future<Output = Result<R, E>> + Send + 'a
+    where
+        'b: 'a,
+        'c: 'b,
+        F: FnOnce(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + Send,
+    {
+        self.with_transaction_on(tx, callback)
     }
 
     /// Executes multiple operations sequentially in a transactions, stopping at the first error.
@@ -196,6 +471,33 @@ where
         &'a self,
         actions: I,
     ) -> impl Future<Output = Result<Vec<R>, E>> + Send + 'a
+    where
+        I: IntoIterator<Item = F> + Send + 'a,
+        I::IntoIter: Send + 'a,
+        F: FnOnce(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + Send + 'a,
+    {
+        self.transaction_sequential_opts(TransactionOptions::new(), actions)
+    }
+
+    /// Executes multiple operations sequentially in a transactions started with `options`,
+    /// stopping at the first error like
+    /// [`transaction_sequential`](TransactionRepository::transaction_sequential).
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: How the transactions is started, like its isolation level
+    /// * `actions`: An iterator of functions that will be executed in the transactions [^func]
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn transaction_sequential_opts<'a, 'b, I, F, Fut, R, E>(
+        &'a self,
+        options: TransactionOptions,
+        actions: I,
+    ) -> impl Future<Output = Result<Vec<R>, E>> + Send + 'a
     where
         I: IntoIterator<Item = F> + Send + 'a,
         I::IntoIter: Send + 'a,
@@ -205,7 +507,7 @@ where
         E: From<Error> + Send + 'a,
     {
         async move {
-            let mut tx = self.pool().begin().await.map_err(E::from)?;
+            let mut tx = options.begin(self.pool()).await.map_err(E::from)?;
             let mut results = Vec::new();
 
             for action in actions {
@@ -253,7 +555,7 @@ where
     /// # Implementation Details
     ///
     /// 1. Begins a transactions from the repository's connection pool
-    /// 2. Wraps the transactions in an [`Arc<Mutex<_>>`] to safely share it between concurrent operations [^mutex]
+    /// 2. Wraps the transactions in a [`SharedTransaction`] to safely share it between concurrent operations [^mutex]
     /// 3. Creates futures for all actions but doesn't execute them yet
     /// 4. Executes all futures concurrently using [`try_join_all`]
     /// 5. If all operations succeed, commits the transactions and returns the results
@@ -261,8 +563,10 @@ where
     ///
     /// # Notes
     ///
-    /// - Uses [`parking_lot::Mutex`] for better performance than `std::sync::Mutex`
-    /// - Requires the transactions to be safely shared between multiple futures
+    /// - Locking the [`SharedTransaction`] waits without blocking the thread, so the guard can be
+    ///   held across `.await`
+    /// - Statements of different actions are run one after another on the same connection, the
+    ///   actions only overlap in the work they do outside the transactions
     ///
     /// # Example
     ///
@@ -270,13 +574,13 @@ where
     ///
     /// ```no_compile
     /// let results = repo.transaction_concurrent([
-    ///     |tx_arc| async move {
-    ///         let mut tx = tx_arc.lock();
-    ///         repo.save_with_executor(&mut *tx, model1).await
+    ///     |tx| async move {
+    ///         let mut tx = tx.lock().await?;
+    ///         repo.save_with_executor(&mut **tx, model1).await
     ///     },
-    ///     |tx_arc| async move {
-    ///         let mut tx = tx_arc.lock();
-    ///         repo.save_with_executor(&mut *tx, model2).await
+    ///     |tx| async move {
+    ///         let mut tx = tx.lock().await?;
+    ///         repo.save_with_executor(&mut **tx, model2).await
     ///     }
     /// ]).await;
     /// ```
@@ -284,8 +588,6 @@ where
     /// ## Complete
     ///
     /// ```rust,should_panic
-    /// use sqlx::Transaction;
-    /// use std::sync::Arc;
     /// use sqlx_utils::prelude::*;
     /// #
     /// # repository! {
@@ -319,7 +621,7 @@ where
     ///     }
     /// }
     ///
-    /// async fn action<'b>(tx: Arc<parking_lot::Mutex<Transaction<'b, Database>>>) -> Result<User, Error> {
+    /// async fn action<'b>(tx: SharedTransaction<'b>) -> Result<User, Error> {
     ///     unimplemented!()
     ///  }
     ///
@@ -329,12 +631,13 @@ where
     /// # }
     /// ```
     ///
-    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Arc<parking_lot::Mutex<Transaction<'b, Database>>>) -> Result<T, E>`
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: SharedTransaction<'b>) -> Result<T, E>`
     ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
     ///
-    /// [^mutex]: It is up to you to ensure we don't get deadlocks, the function itself will not lock the mutex,
-    ///    it will however attempt to get the inner value of the [`Arc`] after all actions has completed where it also consumes the mutex.
-    ///    This makes it in theory impossible to get a deadlock in this method, however deadlocks can occur between different actions.
+    /// [^mutex]: It is up to you to not hold the lock while waiting on another action, which would
+    ///    deadlock. Once all actions has completed the transactions is taken out of the handle,
+    ///    waiting for any action still holding the lock, and handles kept after that fail with
+    ///    [`Error::TransactionClosed`](crate::Error::TransactionClosed) instead of keeping the transactions open.
     fn transaction_concurrent<'a, 'b, I, F, Fut, R, E>(
         &'a self,
         actions: I,
@@ -342,40 +645,62 @@ where
     where
         I: IntoIterator<Item = F> + Send + 'a,
         I::IntoIter: Send + 'a,
-        F: FnOnce(Arc<parking_lot::Mutex<Transaction<'b, Database>>>) -> Fut + Send + 'a,
+        F: FnOnce(SharedTransaction<'b>) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<R, E>> + Send + 'a,
+        R: Send + 'a,
+        E: From<Error> + Send + 'a,
+    {
+        self.transaction_concurrent_opts(TransactionOptions::new(), actions)
+    }
+
+    /// Executes multiple operations concurrently in a transactions started with `options`, like
+    /// [`transaction_concurrent`](TransactionRepository::transaction_concurrent).
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: How the transactions is started, like its isolation level
+    /// * `actions`: An iterator of functions that will be executed in the transactions [^func]
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: SharedTransaction<'b>) -> Result<T, E>`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn transaction_concurrent_opts<'a, 'b, I, F, Fut, R, E>(
+        &'a self,
+        options: TransactionOptions,
+        actions: I,
+    ) -> impl Future<Output = Result<Vec<R>, E>> + Send + 'a
+    where
+        I: IntoIterator<Item = F> + Send + 'a,
+        I::IntoIter: Send + 'a,
+        F: FnOnce(SharedTransaction<'b>) -> Fut + Send + 'a,
         Fut: Future<Output = Result<R, E>> + Send + 'a,
         R: Send + 'a,
         E: From<Error> + Send + 'a,
     {
         async move {
-            let tx = self.pool().begin().await.map_err(E::from)?;
-            let tx = Arc::new(parking_lot::Mutex::new(tx));
+            let tx = options.begin(self.pool()).await.map_err(E::from)?;
+            let shared = SharedTransaction::new(tx);
 
             // Create futures but don't await them yet
             let futures: Vec<_> = actions
                 .into_iter()
-                .map(|action_fn| action_fn(tx.clone()))
+                .map(|action_fn| action_fn(shared.clone()))
                 .collect();
 
             // Execute all futures concurrently
             let results = try_join_all(futures).await;
 
+            // Waits for any handle still holding the lock, handles kept after this fail to lock
+            let tx = shared
+                .take()
+                .await
+                .expect("the transaction is only taken once every action is done");
+
             match results {
                 Ok(values) => {
-                    let tx = match Arc::into_inner(tx) {
-                        Some(mutex) => mutex.into_inner(),
-                        None => return Err(E::from(Error::PoolClosed)),
-                    };
-
                     tx.commit().await.map_err(E::from)?;
                     Ok(values)
                 }
                 Err(e) => {
-                    let tx = match Arc::into_inner(tx) {
-                        Some(mutex) => mutex.into_inner(),
-                        None => return Err(E::from(Error::PoolClosed)),
-                    };
-
                     tx.rollback().await.map_err(E::from)?;
                     Err(e)
                 }
@@ -480,6 +805,32 @@ where
         &'a self,
         actions: I,
     ) -> impl Future<Output = Result<Vec<R>, Vec<E>>> + Send + 'a
+    where
+        I: IntoIterator<Item = F> + Send + 'a,
+        I::IntoIter: Send + 'a,
+        F: FnOnce(Transaction<'b, Database>) -> Fut + Send + 'a,
+        Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)> + Send,
+        R: Send + 'a,
+        E: From<Error> + Send + 'a,
+    {
+        self.try_transaction_opts(TransactionOptions::new(), actions)
+    }
+
+    /// Executes multiple operations in a transactions started with `options` and collects all
+    /// results, like [`try_transaction`](TransactionRepository::try_transaction).
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: How the transactions is started, like its isolation level
+    /// * `actions`: An iterator of functions that will be executed in the transactions [^func]
+    ///
+    /// [^func]: The function signature of an action must be `async fn action<'b>(tx: Transaction<'b, Database>) -> (Result<T, E>, Transaction<'b, Database>)`
+    ///    Take note of the lifetimes as you might run into errors related to lifetimes if they are not specified due to invariance. The future must also be [`Send`]
+    fn try_transaction_opts<'a, 'b, I, F, Fut, R, E>(
+        &'a self,
+        options: TransactionOptions,
+        actions: I,
+    ) -> impl Future<Output = Result<Vec<R>, Vec<E>>> + Send + 'a
     where
         I: IntoIterator<Item = F> + Send + 'a,
         I::IntoIter: Send + 'a,
@@ -489,7 +840,10 @@ where
         E: From<Error> + Send + 'a,
     {
         async move {
-            let mut tx = self.pool().begin().await.map_err(|e| vec![E::from(e)])?;
+            let mut tx = options
+                .begin(self.pool())
+                .await
+                .map_err(|e| vec![E::from(e)])?;
             let mut results = Vec::new();
             let mut errors = Vec::new();
 
@@ -514,6 +868,31 @@ where
     }
 }
 
+/// Runs `callback` in `transaction`, committing it if the result is `Ok` or rolling it back if
+/// it's `Err`.
+async fn run_transaction<'b, F, Fut, R, E>(
+    transaction: Transaction<'b, Database>,
+    callback: F,
+) -> Result<R, E>
+where
+    F: FnOnce(Transaction<'b, Database>) -> Fut,
+    Fut: Future<Output = (Result<R, E>, Transaction<'b, Database>)>,
+    E: From<Error>,
+{
+    let (ret, tx) = callback(transaction).await;
+
+    match ret {
+        Ok(val) => {
+            tx.commit().await.map_err(E::from)?;
+            Ok(val)
+        }
+        Err(err) => {
+            tx.rollback().await.map_err(E::from)?;
+            Err(err)
+        }
+    }
+}
+
 impl<T, M> TransactionRepository<M> for T
 where
     T: Repository<M>,
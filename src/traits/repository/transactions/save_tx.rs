@@ -1,6 +1,8 @@
 //! Extension to [`SaveRepository`] to add transaction based saving.
 
 use crate::prelude::{Model, SaveRepository, TransactionRepository};
+use crate::types::Database;
+use sqlx::Acquire;
 use std::future::Future;
 
 /// Extension trait for Save operations with transactions.
@@ -41,7 +43,44 @@ where
     where
         M: 'a,
     {
-        self.with_transaction(move |mut tx| async move {
+        self.save_in_transaction_on(self.pool(), model)
+    }
+
+    /// Saves a model in a transactions started on `conn`.
+    ///
+    /// When `conn` is an existing transactions the model is saved in a savepoint, so a failed
+    /// save only undoes its own changes, see [`TransactionRepository::with_transaction_on`].
+    ///
+    /// # Parameters
+    ///
+    /// * `conn`: The pool, connection or transactions to save the model with
+    /// * `model`: The model to save
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to:
+    /// * `Ok(M)`: The saved model on success
+    /// * `Err(crate::Error)`: The error if saving failed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut tx = repo.pool().begin().await?;
+    ///
+    /// let saved_model = repo.save_in_transaction_on(&mut tx, model).await?;
+    ///
+    /// tx.commit().await?;
+    /// ```
+    fn save_in_transaction_on<'a, 'c, A>(
+        &'a self,
+        conn: A,
+        model: M,
+    ) -> impl Future<Output = Result<M, crate::Error>> + Send + 'a
+    where
+        A: Acquire<'c, Database = Database> + Send + 'a,
+        M: 'a,
+    {
+        self.with_transaction_on(conn, move |mut tx| async move {
             let res = self.save_with_executor(&mut *tx, model).await;
 
             (res, tx)
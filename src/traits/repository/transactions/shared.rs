@@ -0,0 +1,90 @@
+//! A transaction shared between the concurrent actions of
+//! [`transaction_concurrent`](crate::traits::TransactionRepository::transaction_concurrent).
+
+use crate::types::Database;
+use futures::lock::{Mutex, MutexGuard};
+use sqlx::Transaction;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+/// A handle to a transaction shared between concurrent actions.
+///
+/// Every action gets its own clone of the handle and [locks](SharedTransaction::lock) it for as
+/// long as it needs the transaction. Locking waits without blocking the thread, so statements of
+/// different actions are run one after another while the actions themselves run concurrently.
+///
+/// Once every action is done the transaction is committed or rolled back, a handle kept after
+/// that, like one moved into a spawned task, fails to lock with
+/// [`Error::TransactionClosed`](crate::Error::TransactionClosed).
+#[derive(Debug)]
+pub struct SharedTransaction<'c> {
+    inner: Arc<Mutex<Option<Transaction<'c, Database>>>>,
+}
+
+impl<'c> SharedTransaction<'c> {
+    #[inline]
+    pub(crate) fn new(tx: Transaction<'c, Database>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Some(tx))),
+        }
+    }
+
+    /// Waits until no other action uses the transaction and locks it.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::TransactionClosed`](crate::Error::TransactionClosed) if the transaction
+    /// has already been committed or rolled back.
+    pub async fn lock(&self) -> crate::Result<SharedTransactionGuard<'_, 'c>> {
+        let guard = self.inner.lock().await;
+
+        if guard.is_none() {
+            return Err(crate::Error::TransactionClosed);
+        }
+
+        Ok(SharedTransactionGuard { guard })
+    }
+
+    /// Takes the transaction out once no action uses it anymore, every handle fails to lock it
+    /// afterwards.
+    pub(crate) async fn take(&self) -> Option<Transaction<'c, Database>> {
+        self.inner.lock().await.take()
+    }
+}
+
+impl Clone for SharedTransaction<'_> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+/// Exclusive access to a [`SharedTransaction`], released when dropped.
+///
+/// The guard dereferences to the [`Transaction`], so it is used as an executor with `&mut **tx`.
+#[derive(Debug)]
+pub struct SharedTransactionGuard<'a, 'c> {
+    guard: MutexGuard<'a, Option<Transaction<'c, Database>>>,
+}
+
+impl<'c> Deref for SharedTransactionGuard<'_, 'c> {
+    type Target = Transaction<'c, Database>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.guard
+            .as_ref()
+            .expect("the transaction is checked to be open when locked")
+    }
+}
+
+impl DerefMut for SharedTransactionGuard<'_, '_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard
+            .as_mut()
+            .expect("the transaction is checked to be open when locked")
+    }
+}
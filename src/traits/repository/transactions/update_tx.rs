@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use sqlx::Acquire;
 use std::future::Future;
 
 pub trait UpdatableRepositoryTransaction<M: Model>:
@@ -11,7 +12,21 @@ pub trait UpdatableRepositoryTransaction<M: Model>:
     where
         M: 'a,
     {
-        self.with_transaction(move |mut tx| async move {
+        self.update_in_transaction_on(self.pool(), model)
+    }
+
+    /// Updates a model in a transactions started on `conn`, which is a savepoint when `conn` is
+    /// an existing transactions, see [`TransactionRepository::with_transaction_on`].
+    fn update_in_transaction_on<'a, 'c, A>(
+        &'a self,
+        conn: A,
+        model: M,
+    ) -> impl Future<Output = Result<M, Error>> + Send + 'a
+    where
+        A: Acquire<'c, Database = Database> + Send + 'a,
+        M: 'a,
+    {
+        self.with_transaction_on(conn, move |mut tx| async move {
             let res = self.update_with_executor(&mut *tx, model).await;
 
             (res, tx)
@@ -26,10 +41,32 @@ pub trait UpdatableRepositoryTransaction<M: Model>:
         'b: 'a,
         M: 'b,
     {
-        self.with_transaction(move |mut tx| async move {
+        self.update_ref_in_transaction_on(self.pool(), model)
+    }
+
+    /// Updates a model by reference in a transactions started on `conn`, which is a savepoint
+    /// when `conn` is an existing transactions, see [`TransactionRepository::with_transaction_on`].
+    fn update_ref_in_transaction_on<'a, 'b, 'c, A>(
+        &'a self,
+        conn: A,
+        model: &'b M,
+    ) -> impl Future<Output = Result<(), Error>> + Send + 'a
+    where
+        'b: 'a,
+        A: Acquire<'c, Database = Database> + Send + 'a,
+        M: 'b,
+    {
+        self.with_transaction_on(conn, move |mut tx| async move {
             let res = self.update_ref_with_executor(&mut *tx, model).await;
 
             (res, tx)
         })
     }
 }
+
+impl<T, M> UpdatableRepositoryTransaction<M> for T
+where
+    T: UpdatableRepository<M> + TransactionRepository<M>,
+    M: Model,
+{
+}
@@ -0,0 +1,353 @@
+//! Trait for adding upsert capabilities to a repository
+
+use crate::prelude::Database;
+use crate::traits::{Model, Repository, Table};
+use crate::types::{Dialect, QueryBuilder};
+use crate::utils::{BatchOperator, DEFAULT_BATCH_SIZE};
+use sqlx::{Acquire, Executor};
+
+/// Trait for repositories that can insert records or update them if they already exist.
+///
+/// Unlike [`SaveRepository`](crate::traits::SaveRepository), which decides between an insert and
+/// an update from [`Model::get_id`], the `UpsertRepository` leaves the decision to the database
+/// with a single statement. This is safe under concurrency and also works with natural keys.
+///
+/// The statements are built from the [`Table`] implementation of the model:
+///
+/// * Postgres and SQLite: `INSERT ... ON CONFLICT (conflict columns) DO UPDATE SET col = EXCLUDED.col`
+/// * MySQL: `INSERT ... ON DUPLICATE KEY UPDATE col = VALUES(col)`
+///
+/// # Type Parameters
+///
+/// * `M` - The model type that this repository upserts. Must implement [`Model`] and [`Table`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::prelude::*;
+/// #[derive(Model, sqlx::FromRow)]
+/// #[model(table = "users")]
+/// struct User {
+///     #[model(id, generated)]
+///     id: i64,
+///     email: String,
+///     name: String,
+/// }
+///
+/// repository! {
+///     !crud
+///     UserRepository<User>;
+/// }
+///
+/// repository_upsert! {
+///     UserRepository<User>;
+///
+///     // Upsert on the unique email instead of the generated primary key
+///     const CONFLICT_COLUMNS: &'static [&'static str] = &["email"];
+/// }
+///
+/// # async fn example(repo: &UserRepository) -> sqlx_utils::Result<()> {
+/// let user = User { id: 0, email: String::from("alice@example.com"), name: String::from("Alice") };
+/// repo.upsert(user).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Implementation Notes
+///
+/// 1. No methods are required, the conflict target and updated columns are configured with
+///    [`CONFLICT_COLUMNS`](UpsertRepository::CONFLICT_COLUMNS) and
+///    [`UPDATE_COLUMNS`](UpsertRepository::UPDATE_COLUMNS)
+/// 2. MySQL does not support a conflict target, any unique key violation updates the row
+/// 3. Postgres rejects a statement updating the same row twice, so a batch must not contain two
+///    models with the same conflict key
+#[diagnostic::on_unimplemented(
+    note = "Type `{Self}` does not implement the `UpsertRepository<{M}>` trait",
+    label = "this type does not implement `UpsertRepository` for model type `{M}`",
+    message = "`{Self}` must implement `UpsertRepository<{M}>` to upsert `{M}` records"
+)]
+#[async_trait::async_trait]
+pub trait UpsertRepository<M: Model + Table>: Repository<M> {
+    /// The columns of the unique constraint a conflict is detected on, defaults to the primary
+    /// key of the model.
+    ///
+    /// A key assigned by the database, like a `generated` or `Option` id, is never part of the
+    /// `INSERT` and can not conflict, so such models must set the conflict target explicitly.
+    /// Relying on the default for them fails to compile once the repository is used.
+    ///
+    /// Ignored on MySQL, where every unique key is considered.
+    const CONFLICT_COLUMNS: &'static [&'static str] =
+        inserted_key_columns(M::ID_COLUMNS, M::INSERT_COLUMNS);
+
+    /// The columns updated with the new values when the row already exists.
+    ///
+    /// * [`None`] - Every [`Table::UPDATE_COLUMNS`] column that is inserted and not part of the
+    ///   conflict target, so columns marked `skip_update` keep their stored value
+    /// * `Some(&[])` - Nothing is updated, leaving the existing row untouched
+    const UPDATE_COLUMNS: Option<&'static [&'static str]> = None;
+
+    /// Creates a single statement upserting every model in `models` for the given `dialect`.
+    ///
+    /// The default implementation extends [`Table::insert_many_query`] with the conflict clause
+    /// of the dialect.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Table::insert_many_query`].
+    fn upsert_query<'args>(models: &'args [M], dialect: Dialect) -> QueryBuilder<'args> {
+        let mut builder = M::insert_many_query(models);
+
+        let update = match Self::UPDATE_COLUMNS {
+            Some(columns) => columns.to_vec(),
+            None => M::UPDATE_COLUMNS
+                .iter()
+                .filter(|column| {
+                    M::INSERT_COLUMNS.contains(column) && !Self::CONFLICT_COLUMNS.contains(column)
+                })
+                .copied()
+                .collect(),
+        };
+
+        match dialect {
+            Dialect::MySql => {
+                builder.push(" ON DUPLICATE KEY UPDATE ");
+
+                if update.is_empty() {
+                    // Assigning a column to itself is how MySQL ignores the duplicate row
+                    let column = Self::CONFLICT_COLUMNS
+                        .first()
+                        .or(M::INSERT_COLUMNS.first())
+                        .expect("a multi-row insert has at least one column");
+
+                    builder.push(format!("{column} = {column}"));
+                } else {
+                    let set = update
+                        .iter()
+                        .map(|column| format!("{column} = VALUES({column})"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    builder.push(set);
+                }
+            }
+            Dialect::Postgres | Dialect::Sqlite => {
+                builder.push(format!(
+                    " ON CONFLICT ({}) ",
+                    Self::CONFLICT_COLUMNS.join(", ")
+                ));
+
+                if update.is_empty() {
+                    builder.push("DO NOTHING");
+                } else {
+                    let set = update
+                        .iter()
+                        .map(|column| format!("{column} = EXCLUDED.{column}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    builder.push("DO UPDATE SET ").push(set);
+                }
+            }
+        }
+
+        builder
+    }
+
+    /// Inserts the model, or updates the existing row if it conflicts, using the [`Executor`] `tx`.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx` - The executor to use for the query
+    /// * `model` - The model instance to upsert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<M>`](crate::Result) - The model if the statement was executed successfully
+    #[inline(always)]
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "upsert", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "upsert"))]
+    async fn upsert_with_executor<'c, E>(&self, tx: E, model: M) -> crate::Result<M>
+    where
+        M: 'async_trait,
+        E: Executor<'c, Database = Database> + Send,
+    {
+        let dialect = Dialect::of(self.pool())?;
+
+        Self::upsert_query(std::slice::from_ref(&model), dialect)
+            .build()
+            .execute(tx)
+            .await?;
+
+        Ok(model)
+    }
+
+    /// Inserts the model, or updates the existing row if it conflicts.
+    ///
+    /// This calls [`upsert_with_executor`](UpsertRepository::upsert_with_executor) with the
+    /// repository's connection pool.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The model instance to upsert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<M>`](crate::Result) - The model if the statement was executed successfully
+    #[inline(always)]
+    async fn upsert(&self, model: M) -> crate::Result<M>
+    where
+        M: 'async_trait,
+    {
+        self.upsert_with_executor(self.pool(), model).await
+    }
+
+    /// Upserts every model in `models` using as few statements as the bind parameter limit of
+    /// the database allows, on the connection `tx`.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx` - The connection, transaction or pool to use for the queries
+    /// * `models` - The models to upsert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<()>`](crate::Result) - Success if all statements were executed, or an error if any failed,
+    ///   or if a single row has more values than the database allows in a statement
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "upsert_many", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "upsert_many"))]
+    #[inline(always)]
+    async fn upsert_many_with_executor<'c, A>(&self, tx: A, models: &[M]) -> crate::Result<()>
+    where
+        A: Acquire<'c, Database = Database> + Send,
+    {
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        let dialect = Dialect::of(self.pool())?;
+        let rows = dialect.checked_max_rows(M::INSERT_COLUMNS.len())?;
+        let mut conn = tx.acquire().await?;
+
+        for chunk in models.chunks(rows) {
+            Self::upsert_query(chunk, dialect)
+                .build()
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Upserts multiple models using the default batch size.
+    ///
+    /// This is a convenience wrapper around [`upsert_batch`](UpsertRepository::upsert_batch) that
+    /// uses [`DEFAULT_BATCH_SIZE`].
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - An iterator yielding model instances to upsert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<()>`](crate::Result) - Success if all statements were executed, or an error if any failed
+    #[inline(always)]
+    async fn upsert_many<I>(&self, models: I) -> crate::Result<()>
+    where
+        M: 'async_trait,
+        I: IntoIterator<Item = M> + Send + 'async_trait,
+        I::IntoIter: Send,
+    {
+        <Self as UpsertRepository<M>>::upsert_batch::<DEFAULT_BATCH_SIZE, I>(self, models).await
+    }
+
+    /// Performs a batched upsert with a specified batch size.
+    ///
+    /// This method uses [`BatchOperator`] to split the models into batches of `N`, each upserted
+    /// by [`upsert_many_with_executor`](UpsertRepository::upsert_many_with_executor) in its own
+    /// transaction.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `N` - The size of each batch to process
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - An iterator yielding model instances to upsert
+    ///
+    /// # Returns
+    ///
+    /// * [`crate::Result<()>`](crate::Result) - Success if all batches were processed, or an error if any operation failed
+    #[cfg_attr(feature = "log_err", tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "upsert_batch", err))]
+    #[cfg_attr(not(feature = "log_err"), tracing::instrument(skip_all, level = "debug", parent = &(Self::repository_span()), name = "upsert_batch"))]
+    #[inline(always)]
+    async fn upsert_batch<const N: usize, I>(&self, models: I) -> crate::Result<()>
+    where
+        M: 'async_trait,
+        I: IntoIterator<Item = M> + Send + 'async_trait,
+        I::IntoIter: Send,
+    {
+        let span = tracing::Span::current();
+        span.record("BATCH_SIZE", N);
+
+        BatchOperator::<M, N>::execute_batch(models, |batch| async move {
+            let mut tx = self.pool().begin().await?;
+            self.upsert_many_with_executor(&mut *tx, &batch).await?;
+            tx.commit().await?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Gets the key columns as the default conflict target, failing the constant evaluation if any of
+/// them is left out of the `INSERT`.
+const fn inserted_key_columns(
+    id_columns: &'static [&'static str],
+    insert_columns: &'static [&'static str],
+) -> &'static [&'static str] {
+    let mut idx = 0;
+
+    while idx < id_columns.len() {
+        let mut inserted = false;
+        let mut column = 0;
+
+        while column < insert_columns.len() {
+            if str_eq(id_columns[idx], insert_columns[column]) {
+                inserted = true;
+            }
+
+            column += 1;
+        }
+
+        if !inserted {
+            panic!(
+                "the primary key is assigned by the database and not inserted, set `CONFLICT_COLUMNS` to a unique key instead"
+            );
+        }
+
+        idx += 1;
+    }
+
+    id_columns
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+
+    while idx < a.len() {
+        if a[idx] != b[idx] {
+            return false;
+        }
+
+        idx += 1;
+    }
+
+    true
+}
@@ -1,6 +1,7 @@
 //! Sql filtering trait for defining type safe dynamic filters.
 
-use crate::types::Database;
+use crate::filter::RenderedFilter;
+use crate::types::{Database, Direction};
 use sqlx::{Database as DatabaseTrait, QueryBuilder};
 
 /// Trait for creating SQL filter conditions that can be applied to database queries.
@@ -43,8 +44,10 @@ use sqlx::{Database as DatabaseTrait, QueryBuilder};
 /// # }
 /// ```
 ///
-/// Custom implementation:
+/// Custom implementation, binding values with [`push_bind`](crate::filter::push_bind) so they show
+/// up in [`render`](SqlFilter::render):
 /// ```rust
+/// # use sqlx_utils::filter::push_bind;
 /// # use sqlx_utils::traits::SqlFilter;
 /// # use sqlx_utils::types::Database;
 /// # use sqlx::QueryBuilder;
@@ -62,14 +65,14 @@ use sqlx::{Database as DatabaseTrait, QueryBuilder};
 ///             if let Some(min) = self.min_age {
 ///                 if !first { builder.push(" AND "); }
 ///                 builder.push("age >= ");
-///                 builder.push_bind(min);
+///                 push_bind(builder, min);
 ///                 first = false;
 ///             }
 ///
 ///             if let Some(max) = self.max_age {
 ///                 if !first { builder.push(" AND "); }
 ///                 builder.push("age <= ");
-///                 builder.push_bind(max);
+///                 push_bind(builder, max);
 ///             }
 ///         }
 ///     }
@@ -86,8 +89,13 @@ use sqlx::{Database as DatabaseTrait, QueryBuilder};
 ///
 /// 1. The [`apply_filter`](SqlFilter::apply_filter) method should add SQL conditions to the builder
 /// 2. The [`should_apply_filter`](SqlFilter::should_apply_filter) method should return `true` if this filter has criteria to apply
-/// 3. Consider using the [`sql_filter!`](crate::sql_filter) macro for common filter patterns
-/// 4. Ensure proper parameterization to prevent SQL injection
+/// 3. The optional [`ordering`](SqlFilter::ordering) method adds an `ORDER BY` clause when the
+///    filter is used with a [`FilterRepository`](crate::traits::FilterRepository)
+/// 4. The optional [`apply_clauses`](SqlFilter::apply_clauses), [`row_limit`](SqlFilter::row_limit)
+///    and [`row_offset`](SqlFilter::row_offset) methods add clauses like `GROUP BY` and `LIMIT`
+///    around the conditions when the filter is used on its own with a repository
+/// 5. Consider using the [`sql_filter!`](crate::sql_filter) macro for common filter patterns
+/// 6. Ensure proper parameterization to prevent SQL injection
 #[diagnostic::on_unimplemented(
     message = "The filter type `{Self}` must implement `SqlFilter<'args>` to be used in queries",
     label = "this type does not implement `SqlFilter<'args>`",
@@ -117,4 +125,152 @@ pub trait SqlFilter<'args, DB: DatabaseTrait = Database> {
     /// * `true` - If the filter has conditions to apply
     /// * `false` - If the filter has no conditions to apply
     fn should_apply_filter(&self) -> bool;
+
+    /// The columns the rows should be ordered by, in order of precedence.
+    ///
+    /// The column names are written into the query as is, so they must never come from user
+    /// input. Filters created with the [`sql_filter!`](crate::sql_filter) macro only return
+    /// the columns declared in their `ORDER BY` clause.
+    ///
+    /// # Returns
+    ///
+    /// * The columns and their direction, empty if the order does not matter
+    #[inline]
+    fn ordering(&self) -> Vec<(&'static str, Direction)> {
+        Vec::new()
+    }
+
+    /// Applies this filter as the clauses following the `FROM` clause of a query.
+    ///
+    /// The default adds a `WHERE` clause with the conditions of [`apply_filter`](SqlFilter::apply_filter)
+    /// if the filter [should be applied](SqlFilter::should_apply_filter). Filters with a
+    /// `GROUP BY` or `HAVING` clause add them after it, and must return `true` from
+    /// [`groups_rows`](SqlFilter::groups_rows). Every clause starts with a space, so nothing is
+    /// added when no clause applies.
+    ///
+    /// Only the conditions are kept when filters are combined with [`and`](crate::filter::Filter::and),
+    /// [`or`](crate::filter::Filter::or) or [`not`](crate::filter::Filter::not), since the other
+    /// clauses can only appear once in a query.
+    #[inline]
+    fn apply_clauses(self, builder: &mut QueryBuilder<'args, DB>)
+    where
+        Self: Sized,
+    {
+        if self.should_apply_filter() {
+            builder.push(" WHERE ");
+            self.apply_filter(builder);
+        }
+    }
+
+    /// Whether [`apply_clauses`](SqlFilter::apply_clauses) adds a `GROUP BY` or `HAVING`
+    /// clause, so the query returns a row per group instead of a row per matching record.
+    #[inline]
+    fn groups_rows(&self) -> bool {
+        false
+    }
+
+    /// The maximum number of rows to return, added as a `LIMIT` clause after the ordering.
+    #[inline]
+    fn row_limit(&self) -> Option<u64> {
+        None
+    }
+
+    /// The number of rows to skip, added as an `OFFSET` clause after the limit.
+    #[inline]
+    fn row_offset(&self) -> Option<u64> {
+        None
+    }
+
+    /// Renders the SQL of this filter and the values bound to it, without running it.
+    ///
+    /// See [`RenderedFilter`] for how the bound values are shown.
+    #[inline]
+    fn render(self) -> RenderedFilter
+    where
+        Self: SqlFilter<'args, Database> + Sized,
+    {
+        RenderedFilter::new(self)
+    }
+}
+
+/// A filter that knows the query it filters, which is implemented by the
+/// [`sql_filter!`](crate::sql_filter) macro for every filter.
+///
+/// This is what lets a filter be nested in another as a [`Subquery`](crate::filter::Subquery),
+/// like with [`in_subquery`](crate::filter::in_subquery) and [`exists`](crate::filter::exists).
+pub trait SelectFilter {
+    /// The query up to the `WHERE` clause, like `SELECT user_id FROM orders`.
+    const QUERY: &'static str;
+}
+
+/// An object safe version of [`SqlFilter`], implemented for every filter.
+///
+/// [`SqlFilter::apply_filter`] takes the filter by value, so a `dyn SqlFilter` can never be
+/// applied. This trait takes the filter in a [`Box`] instead, which is what
+/// [`BoxedFilter`](crate::filter::BoxedFilter) uses to erase the type of a filter.
+///
+/// The methods are named apart from the ones on [`SqlFilter`] so both traits can be in scope
+/// without making calls ambiguous.
+pub trait DynFilter<'args, DB: DatabaseTrait = Database> {
+    /// Applies the boxed filter to the builder, see [`SqlFilter::apply_filter`].
+    fn apply_boxed_filter(self: Box<Self>, builder: &mut QueryBuilder<'args, DB>);
+
+    /// Whether the boxed filter has conditions to apply, see [`SqlFilter::should_apply_filter`].
+    fn should_apply_boxed_filter(&self) -> bool;
+
+    /// The ordering of the boxed filter, see [`SqlFilter::ordering`].
+    fn boxed_ordering(&self) -> Vec<(&'static str, Direction)>;
+
+    /// Applies the clauses of the boxed filter, see [`SqlFilter::apply_clauses`].
+    fn apply_boxed_clauses(self: Box<Self>, builder: &mut QueryBuilder<'args, DB>);
+
+    /// Whether the boxed filter groups the rows, see [`SqlFilter::groups_rows`].
+    fn boxed_groups_rows(&self) -> bool;
+
+    /// The limit of the boxed filter, see [`SqlFilter::row_limit`].
+    fn boxed_row_limit(&self) -> Option<u64>;
+
+    /// The offset of the boxed filter, see [`SqlFilter::row_offset`].
+    fn boxed_row_offset(&self) -> Option<u64>;
+}
+
+impl<'args, DB, T> DynFilter<'args, DB> for T
+where
+    DB: DatabaseTrait,
+    T: SqlFilter<'args, DB>,
+{
+    #[inline]
+    fn apply_boxed_filter(self: Box<Self>, builder: &mut QueryBuilder<'args, DB>) {
+        (*self).apply_filter(builder)
+    }
+
+    #[inline]
+    fn should_apply_boxed_filter(&self) -> bool {
+        self.should_apply_filter()
+    }
+
+    #[inline]
+    fn boxed_ordering(&self) -> Vec<(&'static str, Direction)> {
+        self.ordering()
+    }
+
+    #[inline]
+    fn apply_boxed_clauses(self: Box<Self>, builder: &mut QueryBuilder<'args, DB>) {
+        (*self).apply_clauses(builder)
+    }
+
+    #[inline]
+    fn boxed_groups_rows(&self) -> bool {
+        self.groups_rows()
+    }
+
+    #[inline]
+    fn boxed_row_limit(&self) -> Option<u64> {
+        self.row_limit()
+    }
+
+    #[inline]
+    fn boxed_row_offset(&self) -> Option<u64> {
+        self.row_offset()
+    }
 }
@@ -0,0 +1,160 @@
+//! Table trait to describe how a model maps onto a database table
+
+use crate::traits::Model;
+use crate::types::{Database, Query, QueryAs, QueryBuilder, Separated};
+use sqlx::{Database as DatabaseTrait, FromRow};
+
+/// Trait describing the database table backing a [`Model`] and the queries used to manage it.
+///
+/// The trait is usually not implemented by hand but generated by `#[derive(Model)]` when the
+/// struct is annotated with `#[model(table = "...")]`. The generated queries can then be used
+/// by the repository macros, so a model together with [`repository!`](crate::repository) is
+/// enough for full CRUD support.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::prelude::*;
+/// #[derive(Model, sqlx::FromRow)]
+/// #[model(table = "users")]
+/// struct User {
+///     #[model(id)]
+///     id: i64,
+///     #[model(column = "user_name")]
+///     name: String,
+///     #[model(skip_update)]
+///     created_by: String,
+/// }
+///
+/// assert_eq!(User::TABLE_NAME, "users");
+/// assert_eq!(User::ID_COLUMNS, &["id"]);
+/// assert_eq!(User::COLUMNS, &["id", "user_name", "created_by"]);
+/// assert_eq!(User::SELECT_COLUMNS, "id, user_name AS name, created_by");
+/// assert_eq!(User::SELECT_ALL_QUERY, "SELECT id, user_name AS name, created_by FROM users");
+///
+/// repository! {
+///     !crud
+///     UserRepository<User>;
+/// }
+/// ```
+///
+/// # Field Attributes
+///
+/// * `#[model(id)]` - Marks the field as the primary key, defaults to the field named `id`. When
+///   several fields are marked they form a composite key and [`Model::Id`] is a tuple of their
+///   types, in field order
+/// * `#[model(column = "name")]` - Overrides the column name, defaults to the field name. Selected
+///   columns are aliased back to the field name, or to its `#[sqlx(rename = "...")]`, so the
+///   generated queries decode with [`FromRow`]
+/// * `#[model(skip_insert)]` - Excludes the field from the `INSERT` statement
+/// * `#[model(skip_update)]` - Excludes the field from the `SET` clause of the `UPDATE` statement
+/// * `#[model(id, generated)]` - The key is assigned by the database and excluded from the `INSERT`
+///   statement
+/// * `#[model(id, unsaved = expr)]` - The key value marking a model as not yet saved, defaults to
+///   [`Default::default()`] for `generated` keys. The key is excluded from the `INSERT` statement
+///
+/// Fields with `#[sqlx(skip)]` or `#[sqlx(flatten)]` have no column of their own and are left out
+/// of every query.
+///
+/// A key declared as `Option<T>` uses `T` as [`Model::Id`] and is unsaved while `None`. Like keys
+/// with an `unsaved` value it is excluded from the `INSERT` statement, so the database assigns it.
+#[diagnostic::on_unimplemented(
+    note = "Type `{Self}` does not implement the `Table` trait",
+    label = "this type does not implement `Table`",
+    message = "`{Self}` must implement `Table` to generate queries, add `#[model(table = \"...\")]` to the `Model` derive"
+)]
+pub trait Table: Model {
+    /// The name of the table in the database.
+    const TABLE_NAME: &'static str;
+
+    /// The columns that make up the primary key of the table.
+    const ID_COLUMNS: &'static [&'static str];
+
+    /// All columns mapped by the model, in field order.
+    const COLUMNS: &'static [&'static str];
+
+    /// The columns set by the `INSERT` statement, in the order bound by
+    /// [`push_insert_values`](Table::push_insert_values).
+    const INSERT_COLUMNS: &'static [&'static str];
+
+    /// The columns set by the `UPDATE` statement, in the order bound by
+    /// [`update_query`](Table::update_query).
+    const UPDATE_COLUMNS: &'static [&'static str];
+
+    /// The select list reading every column of the model, with each column aliased to the name
+    /// [`FromRow`] decodes it from, like `id, user_name AS name`.
+    const SELECT_COLUMNS: &'static str;
+
+    /// The query used to select every row in the table.
+    const SELECT_ALL_QUERY: &'static str;
+
+    /// The query used to select a single row by its primary key.
+    const SELECT_BY_ID_QUERY: &'static str;
+
+    /// Creates a query inserting the model into the table.
+    fn insert_query(&self) -> Query<'_>;
+
+    /// Binds the value of every [`INSERT_COLUMNS`](Table::INSERT_COLUMNS) column of the model to
+    /// a single row of a multi-row `INSERT`.
+    fn push_insert_values<'args>(&'args self, row: &mut Separated<'_, 'args>);
+
+    /// Creates a query updating the row matching the model's primary key.
+    fn update_query(&self) -> Query<'_>;
+
+    /// Creates a query deleting the row matching the primary key `id`.
+    fn delete_by_id_query(id: &Self::Id) -> Query<'_>;
+
+    /// Binds every primary key column of `id` to `query`, in the order of [`ID_COLUMNS`](Table::ID_COLUMNS).
+    fn bind_id<'q, O>(query: QueryAs<'q, O>, id: Self::Id) -> QueryAs<'q, O>
+    where
+        Self::Id: 'q;
+
+    /// Creates a single `INSERT` statement inserting every model in `models`.
+    ///
+    /// The caller is responsible for keeping the number of bound values,
+    /// `models.len() * INSERT_COLUMNS.len()`, below the limit of the database, see
+    /// [`Dialect::max_rows`](crate::types::Dialect::max_rows).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `models` is empty or the model has no [`INSERT_COLUMNS`](Table::INSERT_COLUMNS),
+    /// as such a statement can not be expressed with a `VALUES` list.
+    fn insert_many_query<'args>(models: &'args [Self]) -> QueryBuilder<'args>
+    where
+        Self: Sized,
+    {
+        assert!(
+            !models.is_empty() && !Self::INSERT_COLUMNS.is_empty(),
+            "a multi-row insert requires at least one model and one column"
+        );
+
+        let mut builder = QueryBuilder::new(format!(
+            "INSERT INTO {} ({}) ",
+            Self::TABLE_NAME,
+            Self::INSERT_COLUMNS.join(", ")
+        ));
+
+        builder.push_values(models, |mut row, model| model.push_insert_values(&mut row));
+
+        builder
+    }
+
+    /// Creates a query selecting every row in the table.
+    #[inline]
+    fn get_all_query<'q>() -> QueryAs<'q, Self>
+    where
+        Self: Sized + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row>,
+    {
+        sqlx::query_as(Self::SELECT_ALL_QUERY)
+    }
+
+    /// Creates a query selecting the row matching the primary key `id`.
+    #[inline]
+    fn get_by_id_query<'q>(id: Self::Id) -> QueryAs<'q, Self>
+    where
+        Self: Sized + for<'r> FromRow<'r, <Database as DatabaseTrait>::Row>,
+        Self::Id: 'q,
+    {
+        Self::bind_id(sqlx::query_as(Self::SELECT_BY_ID_QUERY), id)
+    }
+}
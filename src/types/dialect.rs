@@ -0,0 +1,217 @@
+#[cfg(feature = "any")]
+use crate::pool::DB_POOL;
+use crate::types::Pool;
+use std::cell::Cell;
+
+thread_local! {
+    /// The dialect set with [`Dialect::scope`].
+    static SCOPED: Cell<Option<Dialect>> = const { Cell::new(None) };
+}
+
+/// The SQL dialect spoken by the database behind a [`Pool`].
+///
+/// Most queries are written once for every backend, but some statements, like returning the
+/// inserted row, differ between databases. With a specific database feature enabled the dialect
+/// is known at compile time, with the `any` feature it is read from the scheme of the URL the
+/// pool was created with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl Dialect {
+    /// Gets the dialect of the database `pool` connects to.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the `any` feature if the URL scheme of the pool is not a known database.
+    #[cfg(feature = "any")]
+    pub fn of(pool: &Pool) -> crate::Result<Self> {
+        let options = pool.connect_options();
+        let scheme = options.database_url.scheme();
+
+        Self::from_scheme(scheme).ok_or_else(|| crate::Error::Repository {
+            message: format!("Unsupported database scheme `{scheme}`").into(),
+        })
+    }
+
+    /// Gets the dialect of the database `pool` connects to.
+    #[cfg(all(
+        feature = "postgres",
+        not(any(feature = "sqlite", feature = "mysql", feature = "any"))
+    ))]
+    #[inline]
+    pub fn of(_pool: &Pool) -> crate::Result<Self> {
+        Ok(Self::Postgres)
+    }
+
+    /// Gets the dialect of the database `pool` connects to.
+    #[cfg(all(
+        feature = "mysql",
+        not(any(feature = "sqlite", feature = "any", feature = "postgres"))
+    ))]
+    #[inline]
+    pub fn of(_pool: &Pool) -> crate::Result<Self> {
+        Ok(Self::MySql)
+    }
+
+    /// Gets the dialect of the database `pool` connects to.
+    #[cfg(all(
+        feature = "sqlite",
+        not(any(feature = "any", feature = "mysql", feature = "postgres"))
+    ))]
+    #[inline]
+    pub fn of(_pool: &Pool) -> crate::Result<Self> {
+        Ok(Self::Sqlite)
+    }
+
+    /// Runs `f` with `self` as the [`current`](Dialect::current) dialect, so the filters applied in
+    /// `f` are written for it.
+    ///
+    /// The repositories build their queries in the scope of the dialect of their own pool.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        /// Restores the outer dialect, even if `f` panics.
+        struct Restore(Option<Dialect>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SCOPED.set(self.0);
+            }
+        }
+
+        let _outer = Restore(SCOPED.replace(Some(self)));
+
+        f()
+    }
+
+    /// Gets the dialect queries built without a pool at hand, like filters, are written for.
+    ///
+    /// This is the dialect set with [`scope`](Dialect::scope), with the `any` feature falling back
+    /// to the dialect of the pool set with [`initialize_db_pool`](crate::pool::initialize_db_pool).
+    ///
+    /// # Errors
+    ///
+    /// Fails with the `any` feature if there is neither a scoped dialect nor a known pool.
+    #[cfg(feature = "any")]
+    pub fn current() -> crate::Result<Self> {
+        if let Some(dialect) = SCOPED.get() {
+            return Ok(dialect);
+        }
+
+        match DB_POOL.get() {
+            Some(pool) => Self::of(pool),
+            None => Err(crate::Error::Repository {
+                message: "The database of the query is unknown, build it in `Dialect::scope` or \
+                    call `initialize_db_pool` first"
+                    .into(),
+            }),
+        }
+    }
+
+    /// Gets the dialect queries built without a pool at hand, like filters, are written for.
+    #[cfg(all(
+        feature = "postgres",
+        not(any(feature = "sqlite", feature = "mysql", feature = "any"))
+    ))]
+    #[inline]
+    pub fn current() -> crate::Result<Self> {
+        Ok(SCOPED.get().unwrap_or(Self::Postgres))
+    }
+
+    /// Gets the dialect queries built without a pool at hand, like filters, are written for.
+    #[cfg(all(
+        feature = "mysql",
+        not(any(feature = "sqlite", feature = "any", feature = "postgres"))
+    ))]
+    #[inline]
+    pub fn current() -> crate::Result<Self> {
+        Ok(SCOPED.get().unwrap_or(Self::MySql))
+    }
+
+    /// Gets the dialect queries built without a pool at hand, like filters, are written for.
+    #[cfg(all(
+        feature = "sqlite",
+        not(any(feature = "any", feature = "mysql", feature = "postgres"))
+    ))]
+    #[inline]
+    pub fn current() -> crate::Result<Self> {
+        Ok(SCOPED.get().unwrap_or(Self::Sqlite))
+    }
+
+    /// Gets the dialect matching the scheme of a database URL, like `postgres` in
+    /// `postgres://localhost/db`.
+    pub fn from_scheme(scheme: &str) -> Option<Self> {
+        match scheme {
+            "postgres" | "postgresql" => Some(Self::Postgres),
+            "mysql" | "mariadb" => Some(Self::MySql),
+            "sqlite" => Some(Self::Sqlite),
+            _ => None,
+        }
+    }
+
+    /// The maximum number of bind parameters in a single statement.
+    #[inline]
+    pub fn max_bind_parameters(&self) -> usize {
+        match self {
+            Self::Postgres => 65535,
+            Self::MySql => 65535,
+            Self::Sqlite => 32766,
+        }
+    }
+
+    /// The maximum number of rows with `columns` bound values each that fit in a single statement,
+    /// always at least one, even when a single row does not fit, see
+    /// [`checked_max_rows`](Dialect::checked_max_rows).
+    #[inline]
+    pub fn max_rows(&self, columns: usize) -> usize {
+        (self.max_bind_parameters() / columns.max(1)).max(1)
+    }
+
+    /// Same as [`max_rows`](Dialect::max_rows), but fails if a single row with `columns` bound
+    /// values already exceeds [`max_bind_parameters`](Dialect::max_bind_parameters).
+    #[inline]
+    pub fn checked_max_rows(&self, columns: usize) -> crate::Result<usize> {
+        if columns > self.max_bind_parameters() {
+            return Err(crate::Error::Repository {
+                message: format!(
+                    "A row of {columns} values exceeds the limit of {} bind parameters per statement",
+                    self.max_bind_parameters()
+                )
+                .into(),
+            });
+        }
+
+        Ok(self.max_rows(columns))
+    }
+
+    /// Quotes the identifier `ident`, doubling any quote inside it, like `"name"` on Postgres
+    /// and SQLite or `` `name` `` on MySQL.
+    pub fn quote_identifier(&self, ident: &str) -> String {
+        let quote = match self {
+            Self::Postgres | Self::Sqlite => '"',
+            Self::MySql => '`',
+        };
+
+        let mut quoted = String::with_capacity(ident.len() + 2);
+        quoted.push(quote);
+
+        for char in ident.chars() {
+            if char == quote {
+                quoted.push(quote);
+            }
+
+            quoted.push(char);
+        }
+
+        quoted.push(quote);
+        quoted
+    }
+
+    /// Whether the dialect supports `INSERT ... RETURNING`.
+    #[inline]
+    pub fn supports_returning(&self) -> bool {
+        matches!(self, Self::Postgres | Self::Sqlite)
+    }
+}
@@ -4,6 +4,9 @@ mod_def! {!export
     pub(crate) mod query_types;
     pub(crate) mod pool;
     pub(crate) mod db;
+    pub(crate) mod dialect;
+    pub(crate) mod page;
+    pub(crate) mod transaction;
 }
 
 #[doc(hidden)]
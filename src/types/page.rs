@@ -0,0 +1,301 @@
+use crate::filter::push_bind;
+use crate::types::{Database, QueryBuilder};
+use sqlx::{Database as DatabaseTrait, Row};
+
+/// The direction rows are ordered in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl Direction {
+    /// Gets the SQL keyword of the direction.
+    #[inline]
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// A single value of a [`Cursor`].
+///
+/// Keyset pagination reads the key columns of the last row on a page, so only types that can be
+/// decoded without knowing the model are supported as key columns. Every backend, including
+/// `Any`, supports integer, float, text and boolean columns, so timestamps, UUIDs and decimals can
+/// not be used as keys directly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CursorValue {
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bool(bool),
+}
+
+macro_rules! cursor_value_from {
+    ($($variant:ident($($ty:ty),*)),*) => {
+        $($(
+            impl From<$ty> for CursorValue {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*)*
+    };
+}
+
+cursor_value_from! {
+    Int(i16, i32, i64),
+    Float(f32, f64),
+    Text(String, &str),
+    Bool(bool)
+}
+
+impl CursorValue {
+    /// Reads the value of `column` from `row`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the column is missing or holds a type not supported by [`CursorValue`].
+    pub fn from_row(row: &<Database as DatabaseTrait>::Row, column: &str) -> crate::Result<Self> {
+        macro_rules! try_decode {
+            ($($ty:ty),*) => {
+                $(
+                    if let Ok(value) = row.try_get::<$ty, _>(column) {
+                        return Ok(value.into());
+                    }
+                )*
+            };
+        }
+
+        try_decode!(i64, i32, i16, f64, f32, String, bool);
+
+        Err(crate::Error::Repository {
+            message: format!(
+                "Key column `{column}` is missing or not an integer, float, text or boolean column"
+            )
+            .into(),
+        })
+    }
+
+    pub(crate) fn push_bind<'args>(self, builder: &mut QueryBuilder<'args>) {
+        match self {
+            Self::Int(value) => push_bind(builder, value),
+            Self::Float(value) => push_bind(builder, value),
+            Self::Text(value) => push_bind(builder, value),
+            Self::Bool(value) => push_bind(builder, value),
+        }
+    }
+}
+
+/// The position after the last row of a page in keyset pagination, holding the values of the
+/// key columns of that row in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor(pub Vec<CursorValue>);
+
+/// How a [`PageRequest`] finds the start of the page.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageMode {
+    /// Skips the given number of rows with `OFFSET`.
+    Offset(u64),
+    /// Seeks past the given cursor, or starts at the first row if [`None`].
+    Keyset(Option<Cursor>),
+}
+
+/// A request for a single page of rows.
+///
+/// Offset pagination is the simplest but gets slower the further in the pages are, while keyset
+/// (seek) pagination compares against the key of the previous page, requiring an ordered set of
+/// columns that is unique for every row, like `["name", "id"]`. The key columns must be decodable
+/// as a [`CursorValue`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::types::{Direction, PageRequest};
+/// // The third page of 20 rows ordered by name
+/// let offset = PageRequest::offset(20, 40).order_by(&["name", "id"], Direction::Asc);
+///
+/// // The first page of the newest rows, by their auto incrementing id
+/// let keyset = PageRequest::keyset(20, &["id"]).direction(Direction::Desc);
+///
+/// // The first page ordered by name, with the id breaking ties between equal names
+/// let by_name = PageRequest::keyset(20, &["name", "id"]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRequest {
+    /// The maximum number of rows on the page.
+    pub size: u32,
+    /// The columns the rows are ordered by.
+    pub order: &'static [&'static str],
+    /// The direction every column in [`order`](PageRequest::order) is ordered in.
+    pub direction: Direction,
+    /// Where the page starts.
+    pub mode: PageMode,
+}
+
+impl PageRequest {
+    /// Creates a request for `size` rows, skipping the first `offset` rows.
+    #[inline]
+    pub fn offset(size: u32, offset: u64) -> Self {
+        Self {
+            size,
+            order: &[],
+            direction: Direction::Asc,
+            mode: PageMode::Offset(offset),
+        }
+    }
+
+    /// Creates a request for the first `size` rows ordered by the unique column set `key`.
+    #[inline]
+    pub fn keyset(size: u32, key: &'static [&'static str]) -> Self {
+        Self {
+            size,
+            order: key,
+            direction: Direction::Asc,
+            mode: PageMode::Keyset(None),
+        }
+    }
+
+    /// Orders the rows by `columns` in `direction`.
+    #[inline]
+    pub fn order_by(mut self, columns: &'static [&'static str], direction: Direction) -> Self {
+        self.order = columns;
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the direction the rows are ordered in.
+    #[inline]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Starts a keyset page after `cursor`.
+    #[inline]
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.mode = PageMode::Keyset(Some(cursor));
+        self
+    }
+
+    /// Appends the keyset condition, ordering and limit of this page to `builder`, which should
+    /// select from the rows to paginate, like `SELECT * FROM (...) AS page`.
+    ///
+    /// One more row than [`size`](PageRequest::size) is selected to know if there is a next page.
+    ///
+    /// # Errors
+    ///
+    /// Fails for keyset requests without key columns or with a cursor of the wrong length.
+    pub fn push_page<'args>(&self, builder: &mut QueryBuilder<'args>) -> crate::Result<()> {
+        if let PageMode::Keyset(cursor) = &self.mode {
+            if self.order.is_empty() {
+                return Err(crate::Error::Repository {
+                    message: "Keyset pagination requires at least one key column".into(),
+                });
+            }
+
+            if let Some(Cursor(values)) = cursor {
+                if values.len() != self.order.len() {
+                    return Err(crate::Error::Repository {
+                        message: format!(
+                            "Cursor has {} values but the key has {} columns",
+                            values.len(),
+                            self.order.len()
+                        )
+                        .into(),
+                    });
+                }
+
+                let operator = match self.direction {
+                    Direction::Asc => ">",
+                    Direction::Desc => "<",
+                };
+
+                builder.push(format!(" WHERE ({}) {operator} (", self.order.join(", ")));
+
+                for (idx, value) in values.iter().cloned().enumerate() {
+                    if idx > 0 {
+                        builder.push(", ");
+                    }
+
+                    value.push_bind(builder);
+                }
+
+                builder.push(")");
+            }
+        }
+
+        if !self.order.is_empty() {
+            let direction = self.direction.as_sql();
+            let order = self
+                .order
+                .iter()
+                .map(|column| format!("{column} {direction}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            builder.push(format!(" ORDER BY {order}"));
+        }
+
+        builder.push(format!(" LIMIT {}", u64::from(self.size) + 1));
+
+        if let PageMode::Offset(offset) = self.mode {
+            builder.push(format!(" OFFSET {offset}"));
+        }
+
+        Ok(())
+    }
+
+    /// Splits the fetched `rows` into the rows of this page and the request for the next page.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the cursor for the next keyset page can not be read from the last row.
+    pub fn next_page<R>(
+        &self,
+        mut rows: Vec<<Database as DatabaseTrait>::Row>,
+        decode: impl Fn(&<Database as DatabaseTrait>::Row) -> Result<R, sqlx::Error>,
+    ) -> crate::Result<(Vec<R>, Option<PageRequest>)> {
+        let size = self.size as usize;
+        let has_next = rows.len() > size;
+        rows.truncate(size);
+
+        let next = match (&self.mode, rows.last()) {
+            (_, None) => None,
+            _ if !has_next => None,
+            (PageMode::Offset(offset), Some(_)) => Some(Self {
+                mode: PageMode::Offset(offset + u64::from(self.size)),
+                ..self.clone()
+            }),
+            (PageMode::Keyset(_), Some(last)) => {
+                let cursor = self
+                    .order
+                    .iter()
+                    .map(|column| CursorValue::from_row(last, column))
+                    .collect::<crate::Result<Vec<_>>>()?;
+
+                Some(self.clone().after(Cursor(cursor)))
+            }
+        };
+
+        let items = rows.iter().map(decode).collect::<Result<Vec<_>, _>>()?;
+
+        Ok((items, next))
+    }
+}
+
+/// A single page of rows returned by [`get_page_by_filter`](crate::traits::FilterRepositoryExt::get_page_by_filter).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<M> {
+    /// The rows on this page.
+    pub items: Vec<M>,
+    /// The number of rows matching the filter across all pages.
+    pub total: u64,
+    /// The request for the next page, [`None`] if this is the last page.
+    pub next: Option<PageRequest>,
+}
@@ -29,3 +29,9 @@ pub type QueryAs<'q, T, DB = Database> =
     sqlx::query::QueryAs<'q, Database, T, <DB as DatabaseTrait>::Arguments<'q>>;
 
 pub type QueryBuilder<'args, DB = Database> = sqlx::QueryBuilder<'args, DB>;
+
+/// A [`Separated`](sqlx::query_builder::Separated) created from a [`QueryBuilder`], pushing each
+/// value with `Sep` in between. Used to bind the values of a single row in
+/// [`QueryBuilder::push_values`](sqlx::QueryBuilder::push_values).
+pub type Separated<'qb, 'args, Sep = &'static str, DB = Database> =
+    sqlx::query_builder::Separated<'qb, 'args, DB, Sep>;
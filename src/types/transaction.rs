@@ -0,0 +1,317 @@
+use crate::types::{Database, Dialect, Pool};
+use futures::channel::oneshot;
+use futures::future::BoxFuture;
+use sqlx::Transaction;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// The isolation level of a transaction.
+///
+/// SQLite has no isolation levels, its transactions are always serializable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    /// Gets the SQL keywords of the isolation level.
+    #[inline]
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::ReadUncommitted => "READ UNCOMMITTED",
+            Self::ReadCommitted => "READ COMMITTED",
+            Self::RepeatableRead => "REPEATABLE READ",
+            Self::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// When a SQLite transaction takes its locks.
+///
+/// A [`Deferred`](BeginMode::Deferred) transaction only takes the write lock on its first write,
+/// which fails with `SQLITE_BUSY` if another connection is writing by then. An
+/// [`Immediate`](BeginMode::Immediate) transaction takes it when it begins instead, so writers
+/// wait for each other up front.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BeginMode {
+    #[default]
+    Deferred,
+    Immediate,
+    Exclusive,
+}
+
+impl BeginMode {
+    /// Gets the SQL keyword of the mode.
+    #[inline]
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Deferred => "DEFERRED",
+            Self::Immediate => "IMMEDIATE",
+            Self::Exclusive => "EXCLUSIVE",
+        }
+    }
+}
+
+/// How a transaction is started.
+///
+/// Every option is written in the statement starting the transaction for the [`Dialect`] of the
+/// pool, options a database does not support are left out:
+///
+/// | Option | Postgres | MySQL | SQLite |
+/// |---|---|---|---|
+/// | [`isolation_level`](TransactionOptions::isolation_level) | `BEGIN ISOLATION LEVEL ...` | `SET TRANSACTION ISOLATION LEVEL ...` | - |
+/// | [`read_only`](TransactionOptions::read_only) | `READ ONLY` | `START TRANSACTION READ ONLY` | - |
+/// | [`deferrable`](TransactionOptions::deferrable) | `DEFERRABLE` | - | - |
+/// | [`begin_mode`](TransactionOptions::begin_mode) | - | - | `BEGIN IMMEDIATE` |
+///
+/// # Examples
+///
+/// ```rust
+/// # use sqlx_utils::types::{BeginMode, Dialect, IsolationLevel, TransactionOptions};
+/// let report = TransactionOptions::new()
+///     .isolation_level(IsolationLevel::RepeatableRead)
+///     .read_only(true);
+///
+/// assert_eq!(
+///     report.begin_sql(Dialect::Postgres).as_deref(),
+///     Some("BEGIN ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+/// );
+///
+/// let writer = TransactionOptions::new().begin_mode(BeginMode::Immediate);
+///
+/// assert_eq!(writer.begin_sql(Dialect::Sqlite).as_deref(), Some("BEGIN IMMEDIATE"));
+/// assert_eq!(writer.begin_sql(Dialect::Postgres), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TransactionOptions {
+    /// The isolation level, or the default of the database if [`None`].
+    pub isolation_level: Option<IsolationLevel>,
+    /// Whether the transaction may only read.
+    pub read_only: bool,
+    /// Whether a serializable read only transaction waits until it can run without any risk of
+    /// a serialization failure, only used by Postgres.
+    pub deferrable: bool,
+    /// When the transaction takes its locks, only used by SQLite.
+    pub begin_mode: BeginMode,
+}
+
+impl TransactionOptions {
+    /// Creates options for a default transaction.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the isolation level.
+    #[inline]
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Sets whether the transaction may only read.
+    #[inline]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets whether the transaction is deferrable.
+    #[inline]
+    pub fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = deferrable;
+        self
+    }
+
+    /// Sets when a SQLite transaction takes its locks.
+    #[inline]
+    pub fn begin_mode(mut self, mode: BeginMode) -> Self {
+        self.begin_mode = mode;
+        self
+    }
+
+    /// Gets the statement starting the transaction on `dialect`, or [`None`] if the default
+    /// `BEGIN` does the same.
+    pub fn begin_sql(&self, dialect: Dialect) -> Option<String> {
+        match dialect {
+            Dialect::Postgres => {
+                let mut modes = Vec::new();
+
+                if let Some(level) = self.isolation_level {
+                    modes.push(format!("ISOLATION LEVEL {}", level.as_sql()));
+                }
+
+                if self.read_only {
+                    modes.push("READ ONLY".to_string());
+                }
+
+                if self.deferrable {
+                    modes.push("DEFERRABLE".to_string());
+                }
+
+                (!modes.is_empty()).then(|| format!("BEGIN {}", modes.join(", ")))
+            }
+            Dialect::MySql => {
+                let start = if self.read_only {
+                    "START TRANSACTION READ ONLY"
+                } else {
+                    "START TRANSACTION"
+                };
+
+                match self.isolation_level {
+                    // Applies to the next transaction only, which is started right after
+                    Some(level) => Some(format!(
+                        "SET TRANSACTION ISOLATION LEVEL {}; {start}",
+                        level.as_sql()
+                    )),
+                    None if self.read_only => Some(start.to_string()),
+                    None => None,
+                }
+            }
+            Dialect::Sqlite => match self.begin_mode {
+                BeginMode::Deferred => None,
+                mode => Some(format!("BEGIN {}", mode.as_sql())),
+            },
+        }
+    }
+
+    /// Begins a transaction from `pool` with these options.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the transaction could not be started, or with the `any` feature if the database
+    /// of the pool is not known.
+    pub async fn begin(&self, pool: &Pool) -> Result<Transaction<'static, Database>, sqlx::Error> {
+        let dialect =
+            Dialect::of(pool).map_err(|err| sqlx::Error::Configuration(err.to_string().into()))?;
+
+        match self.begin_sql(dialect) {
+            Some(sql) => pool.begin_with(sql).await,
+            None => pool.begin().await,
+        }
+    }
+}
+
+/// How often a transaction failing with a [retryable](crate::error::RetryableError) error is run,
+/// and how long to wait in between.
+///
+/// The wait doubles after every failed attempt, starting at
+/// [`base_delay`](RetryPolicy::base_delay) and capped at [`max_delay`](RetryPolicy::max_delay).
+/// With [`jitter`](RetryPolicy::jitter) a random wait between half and all of that is used
+/// instead, so transactions that failed together don't all run again at the same time.
+///
+/// The wait runs on a separate thread by default, which works on every async runtime. Set
+/// [`sleep`](RetryPolicy::sleep) to wait on the timer of the runtime instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use sqlx_utils::types::RetryPolicy;
+/// let policy = RetryPolicy::new(5)
+///     .base_delay(Duration::from_millis(20))
+///     .jitter(false);
+///
+/// assert_eq!(policy.delay(1), Duration::from_millis(20));
+/// assert_eq!(policy.delay(3), Duration::from_millis(80));
+///
+/// // Waiting on the timer of tokio
+/// let policy = policy.sleep(|delay| Box::pin(tokio::time::sleep(delay)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// The number of times the transaction is run at most, including the first time.
+    pub max_attempts: u32,
+    /// The wait after the first failed attempt.
+    pub base_delay: Duration,
+    /// The longest wait between two attempts.
+    pub max_delay: Duration,
+    /// Whether the wait is randomized.
+    pub jitter: bool,
+    /// Waits for the given time between two attempts.
+    pub sleep: fn(Duration) -> BoxFuture<'static, ()>,
+}
+
+impl Default for RetryPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy running a transaction at most `max_attempts` times, waiting 10ms after
+    /// the first failed attempt and at most a second.
+    #[inline]
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_secs(1),
+            jitter: true,
+            sleep: thread_sleep,
+        }
+    }
+
+    /// Sets the wait after the first failed attempt.
+    #[inline]
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the longest wait between two attempts.
+    #[inline]
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets whether the wait is randomized.
+    #[inline]
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets how to wait between two attempts, like `|delay| Box::pin(tokio::time::sleep(delay))`.
+    #[inline]
+    pub fn sleep(mut self, sleep: fn(Duration) -> BoxFuture<'static, ()>) -> Self {
+        self.sleep = sleep;
+        self
+    }
+
+    /// Gets how long to wait after the failed attempt `attempt`, counting from 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        if !self.jitter {
+            return backoff;
+        }
+
+        // Every `RandomState` is seeded differently, which is random enough to spread retries
+        let random = RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64;
+        let half = backoff / 2;
+
+        half + (backoff - half).mul_f64(random)
+    }
+}
+
+/// Waits for `delay` on a new thread, so it works without knowing the async runtime.
+fn thread_sleep(delay: Duration) -> BoxFuture<'static, ()> {
+    let (sender, receiver) = oneshot::channel();
+
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        let _ = sender.send(());
+    });
+
+    Box::pin(async move {
+        let _ = receiver.await;
+    })
+}
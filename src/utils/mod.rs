@@ -16,6 +16,8 @@ macro_rules! mod_def {
 
 mod_def! {
     pub mod batch;
+    pub(crate) mod query;
+    pub(crate) mod stream;
 }
 
 #[doc(hidden)]
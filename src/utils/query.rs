@@ -0,0 +1,164 @@
+use crate::filter::{RenderedFilter, push_bind};
+use crate::traits::SqlFilter;
+use crate::types::{Database, QueryBuilder};
+use sqlx::{Database as DatabaseTrait, Execute, Row};
+use tracing::Span;
+
+/// Clauses that change which rows a `SELECT` returns when its select list is replaced, like
+/// `GROUP BY` turning a single `COUNT(*)` into one count per group.
+const ROW_CHANGING_CLAUSES: &[&str] = &[
+    "DISTINCT", "GROUP", "HAVING", "LIMIT", "OFFSET", "FETCH", "UNION", "INTERSECT", "EXCEPT",
+    "ORDER", "WINDOW",
+];
+
+/// Finds the byte offset of the first `keyword` outside of parentheses and quotes in `sql`,
+/// matching whole words only and ignoring case.
+fn find_top_level(sql: &str, keyword: &str) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let is_word = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_';
+
+    for (idx, &byte) in bytes.iter().enumerate() {
+        match (quote, byte) {
+            (Some(open), _) if byte == open => quote = None,
+            (Some(_), _) => {}
+            (None, b'\'' | b'"' | b'`') => quote = Some(byte),
+            (None, b'(') => depth += 1,
+            (None, b')') => depth = depth.saturating_sub(1),
+            (None, _) if depth == 0 => {
+                let end = idx + keyword.len();
+
+                if end <= bytes.len()
+                    && bytes[idx..end].eq_ignore_ascii_case(keyword.as_bytes())
+                    && (idx == 0 || !is_word(bytes[idx - 1]))
+                    && bytes.get(end).is_none_or(|&next| !is_word(next))
+                {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Replaces the select list of a plain `SELECT ... FROM ...` query with `projection`, like
+/// turning `SELECT * FROM users WHERE ...` into `SELECT COUNT(*) FROM users WHERE ...`.
+///
+/// Returns [`None`] if the query is not a single `SELECT` or has a clause that would change the
+/// rows returned with the new select list, the caller should wrap the query in a subquery then.
+pub(crate) fn replace_select_list(sql: &str, projection: &str) -> Option<String> {
+    let sql = sql.trim_start();
+
+    if find_top_level(sql, "SELECT") != Some(0) {
+        return None;
+    }
+
+    let from = find_top_level(sql, "FROM")?;
+
+    if ROW_CHANGING_CLAUSES
+        .iter()
+        .any(|clause| find_top_level(sql, clause).is_some())
+    {
+        return None;
+    }
+
+    Some(format!("SELECT {projection} {}", &sql[from..]))
+}
+
+/// Appends the clauses of `filter` to `builder`: the `WHERE`, `GROUP BY` and `HAVING` clauses,
+/// the `ORDER BY` clause if `ordered` and then the `LIMIT` and `OFFSET` clauses.
+///
+/// The limit and offset are bound as `BIGINT`, and an offset without a limit gets the largest
+/// limit there is as MySQL and SQLite do not support `OFFSET` on its own.
+pub(crate) fn push_clauses<'args>(
+    builder: &mut QueryBuilder<'args, Database>,
+    filter: impl SqlFilter<'args>,
+    ordered: bool,
+) {
+    push_clauses_with(builder, filter, ordered, |builder| builder);
+}
+
+/// Same as [`push_clauses`], calling `post_filter` right after the `WHERE`, `GROUP BY` and
+/// `HAVING` clauses, before the `ORDER BY`, `LIMIT` and `OFFSET` clauses are appended.
+pub(crate) fn push_clauses_with<'args>(
+    builder: &mut QueryBuilder<'args, Database>,
+    filter: impl SqlFilter<'args>,
+    ordered: bool,
+    post_filter: impl FnOnce(QueryBuilder<'args, Database>) -> QueryBuilder<'args, Database>,
+) {
+    let ordering = if ordered {
+        filter.ordering()
+    } else {
+        Vec::new()
+    };
+    let limit = filter.row_limit();
+    let offset = filter.row_offset();
+
+    filter.apply_clauses(builder);
+    *builder = post_filter(std::mem::replace(builder, QueryBuilder::new("")));
+
+    if !ordering.is_empty() {
+        let order = ordering
+            .iter()
+            .map(|(column, direction)| format!("{column} {}", direction.as_sql()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        builder.push(format!(" ORDER BY {order}"));
+    }
+
+    let bigint = |value: u64| i64::try_from(value).unwrap_or(i64::MAX);
+
+    if limit.is_some() || offset.is_some() {
+        builder.push(" LIMIT ");
+        push_bind(builder, limit.map_or(i64::MAX, bigint));
+    }
+
+    if let Some(offset) = offset {
+        builder.push(" OFFSET ");
+        push_bind(builder, bigint(offset));
+    }
+}
+
+/// Reads the result of a `SELECT EXISTS(...)` query.
+///
+/// Postgres returns a boolean, while MySQL and SQLite return an integer.
+pub(crate) fn exists_from_row(row: &<Database as DatabaseTrait>::Row) -> crate::Result<bool> {
+    match row.try_get::<bool, _>(0) {
+        Ok(exists) => Ok(exists),
+        Err(_) => Ok(row.try_get::<i64, _>(0)? != 0),
+    }
+}
+
+/// Takes the SQL and the bound arguments out of `builder`, so the query can be run with
+/// [`query_with`](sqlx::query_with) and friends.
+///
+/// Both are recorded on the `query` and `arguments` fields of the current span, if it is enabled,
+/// with the arguments shown as the `captured` values bound to them, see
+/// [`RenderedFilter::from_arguments`].
+pub(crate) fn take_query<'q>(
+    builder: &'q mut QueryBuilder<'_, Database>,
+    captured: &[String],
+) -> crate::Result<(&'q str, <Database as DatabaseTrait>::Arguments<'q>)> {
+    let mut query = builder.build();
+
+    let arguments = query
+        .take_arguments()
+        .map_err(|err| crate::Error::Boxed(err))?
+        .unwrap_or_default();
+    let sql = query.sql();
+
+    let span = Span::current();
+
+    if !span.is_disabled() {
+        let rendered = RenderedFilter::from_arguments(sql, &arguments, captured);
+
+        span.record("query", rendered.sql.as_str());
+        span.record("arguments", tracing::field::debug(&rendered.arguments));
+    }
+
+    Ok((sql, arguments))
+}
@@ -0,0 +1,81 @@
+use futures::channel::mpsc;
+use futures::{FutureExt, SinkExt, Stream, StreamExt, future, stream};
+use std::future::Future;
+use std::task::Poll;
+use tracing::{Instrument, Span};
+
+/// Creates a stream of the items sent by the `produce` future, running it inside `span`.
+///
+/// The stream of a query borrows its SQL, so a stream over a query built on the fly, like the
+/// query of a filter, can not be returned next to its query builder. The producer owns the
+/// query instead and forwards every row as it arrives, waiting for the consumer before fetching
+/// the next one. Dropping the stream drops the producer, ending the query.
+pub(crate) fn producer_stream<'a, T, F, Fut>(
+    span: Span,
+    produce: F,
+) -> impl Stream<Item = T> + Send + 'a
+where
+    T: Send + 'a,
+    F: FnOnce(mpsc::Sender<T>) -> Fut,
+    Fut: Future<Output = ()> + Send + 'a,
+{
+    let (sender, receiver) = mpsc::channel(0);
+
+    let producer = produce(sender)
+        .instrument(span)
+        .into_stream()
+        .filter_map(|()| future::ready(None));
+
+    stream::select(producer, receiver)
+}
+
+/// Forwards every item of `items` to `sender` until either side is done.
+///
+/// Errors are logged with the `log_err` feature, as the `err` option of `tracing::instrument`
+/// does for the other repository methods.
+pub(crate) async fn forward_stream<T, E>(
+    items: impl Stream<Item = Result<T, E>>,
+    mut sender: mpsc::Sender<crate::Result<T>>,
+) where
+    E: Into<crate::Error>,
+{
+    let mut items = std::pin::pin!(items);
+
+    while let Some(item) = items.next().await {
+        if sender.send(convert_item(item)).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Polls `rows`, the stream of a query that owns its SQL, inside `span`, converting the errors.
+///
+/// Errors are logged with the `log_err` feature, like in [`forward_stream`].
+pub(crate) fn instrument_rows<'a, T, E>(
+    span: Span,
+    mut rows: impl Stream<Item = Result<T, E>> + Unpin + Send + 'a,
+) -> impl Stream<Item = crate::Result<T>> + Send + 'a
+where
+    T: 'a,
+    E: Into<crate::Error>,
+{
+    stream::poll_fn(move |cx| {
+        let _entered = span.enter();
+
+        match rows.poll_next_unpin(cx) {
+            Poll::Ready(item) => Poll::Ready(item.map(convert_item)),
+            Poll::Pending => Poll::Pending,
+        }
+    })
+}
+
+fn convert_item<T, E: Into<crate::Error>>(item: Result<T, E>) -> crate::Result<T> {
+    let item = item.map_err(Into::into);
+
+    #[cfg(feature = "log_err")]
+    if let Err(error) = &item {
+        tracing::error!(error = %error);
+    }
+
+    item
+}
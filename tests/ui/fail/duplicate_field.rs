@@ -0,0 +1,14 @@
+use sqlx_utils::sql_filter;
+
+sql_filter! {
+    pub struct OrderFilter {
+        SELECT * FROM orders o
+        JOIN customers c ON c.id = o.customer_id
+        WHERE
+        ?o.id = i64 AND
+        ?c.id as o_id = i64
+    }
+}
+
+fn main() {
+}
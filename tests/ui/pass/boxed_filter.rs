@@ -0,0 +1,57 @@
+use sqlx_utils::filter::{BoxedFilter, all_of, any_of, equals, like};
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+sql_filter! {
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+    }
+}
+
+fn search(names: Vec<String>, ids: Vec<i64>) -> BoxedFilter<'static> {
+    let mut filters = vec![any_of(names.into_iter().map(|name| like("name", Some(name)))).boxed()];
+
+    if !ids.is_empty() {
+        filters.push(any_of(ids.into_iter().map(|id| equals("id", Some(id)))).boxed());
+    }
+
+    filters.push(BoxedFilter::new(UserFilter::new()));
+
+    all_of(filters).boxed()
+}
+
+#[allow(dead_code)]
+async fn find(repo: &UserRepo) -> sqlx_utils::Result<Vec<User>> {
+    repo.get_all_by_any_filter(search(vec!["A%".into()], vec![1, 2]))
+        .await
+}
+
+fn main() {
+    let query = <UserRepo as FilterRepository<User>>::prepare_filter_query(search(
+        vec!["A%".into(), "B%".into()],
+        vec![1, 2],
+    ));
+
+    assert_eq!(
+        query.sql(),
+        "SELECT * FROM users WHERE ((name LIKE ? OR name LIKE ?) AND (id = ? OR id = ?))"
+    );
+
+    let query =
+        <UserRepo as FilterRepository<User>>::prepare_filter_query(search(vec![], vec![]));
+
+    assert_eq!(query.sql(), "SELECT * FROM users");
+}
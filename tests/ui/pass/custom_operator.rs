@@ -0,0 +1,17 @@
+use sqlx_utils::filter::Raw;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sql_operator;
+
+sql_operator!(pub Glob<String>, "GLOB");
+
+fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+    let mut builder = QueryBuilder::new("");
+    filter.apply_filter(&mut builder);
+    builder.into_sql()
+}
+
+fn main() {
+    assert_eq!(render(glob("name", Some("A*"))), "name GLOB ?");
+    assert_eq!(render(glob_raw("name", Raw::unsafe_raw("A*"))), "name GLOB ?");
+    assert_eq!(Raw::unsafe_raw("c.id").as_str(), "c.id");
+}
@@ -0,0 +1,81 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "user_roles")]
+pub struct UserRole {
+    #[model(id)]
+    user_id: i64,
+    #[model(id, column = "role")]
+    role_id: i32,
+    granted_by: String,
+}
+
+#[derive(Model, FromRow)]
+#[model(table = "memberships")]
+pub struct Membership {
+    #[model(id)]
+    org_id: i64,
+    #[model(id)]
+    team_id: i64,
+    #[model(id)]
+    user_id: i64,
+}
+
+repository! {
+    !crud
+    pub UserRoleRepo<UserRole>;
+}
+
+repository! {
+    !crud
+    pub MembershipRepo<Membership>;
+}
+
+fn main() {
+    let role = UserRole {
+        user_id: 1,
+        role_id: 2,
+        granted_by: String::from("admin"),
+    };
+
+    let _: Option<(i64, i32)> = role.get_id();
+    assert_eq!(role.get_id(), Some((1, 2)));
+    assert_eq!(UserRole::ID_COLUMNS, &["user_id", "role"]);
+    assert_eq!(
+        UserRole::SELECT_BY_ID_QUERY,
+        "SELECT user_id, role AS role_id, granted_by FROM user_roles WHERE user_id = ? AND role = ?"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&role.update_query()),
+        "UPDATE user_roles SET granted_by = ? WHERE user_id = ? AND role = ?"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&UserRole::delete_by_id_query(&(1, 2))),
+        "DELETE FROM user_roles WHERE user_id = ? AND role = ?"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&UserRole::get_by_id_query((1, 2))),
+        "SELECT user_id, role AS role_id, granted_by FROM user_roles WHERE user_id = ? AND role = ?"
+    );
+
+    let membership = Membership {
+        org_id: 1,
+        team_id: 2,
+        user_id: 3,
+    };
+
+    assert_eq!(membership.get_id(), Some((1, 2, 3)));
+    assert_eq!(
+        sqlx::Execute::sql(&membership.insert_query()),
+        "INSERT INTO memberships (org_id, team_id, user_id) VALUES (?, ?, ?)"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&membership.update_query()),
+        "UPDATE memberships SET org_id = org_id WHERE org_id = ? AND team_id = ? AND user_id = ?"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&Membership::delete_by_id_query(&(1, 2, 3))),
+        "DELETE FROM memberships WHERE org_id = ? AND team_id = ? AND user_id = ?"
+    );
+}
@@ -0,0 +1,74 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct OptionalId {
+    id: Option<i64>,
+    name: String,
+}
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct GeneratedId {
+    #[model(id, generated)]
+    id: i64,
+    name: String,
+}
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct SentinelId {
+    #[model(id, unsaved = -1)]
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub OptionalIdRepo<OptionalId>;
+}
+
+repository! {
+    !crud
+    pub GeneratedIdRepo<GeneratedId>;
+}
+
+fn main() {
+    let unsaved = OptionalId { id: None, name: String::from("Alice") };
+    let saved = OptionalId { id: Some(1), name: String::from("Alice") };
+
+    let _: Option<i64> = unsaved.get_id();
+    assert_eq!(unsaved.get_id(), None);
+    assert_eq!(saved.get_id(), Some(1));
+    assert_eq!(OptionalId::INSERT_COLUMNS, &["name"]);
+    assert_eq!(
+        sqlx::Execute::sql(&unsaved.insert_query()),
+        "INSERT INTO users (name) VALUES (?)"
+    );
+
+    let unsaved = GeneratedId { id: 0, name: String::from("Bob") };
+    let saved = GeneratedId { id: 2, name: String::from("Bob") };
+
+    assert_eq!(unsaved.get_id(), None);
+    assert_eq!(saved.get_id(), Some(2));
+    assert_eq!(
+        sqlx::Execute::sql(&unsaved.insert_query()),
+        "INSERT INTO users (name) VALUES (?)"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&saved.update_query()),
+        "UPDATE users SET name = ? WHERE id = ?"
+    );
+
+    let unsaved = SentinelId { id: -1, name: String::from("Carol") };
+    let saved = SentinelId { id: 0, name: String::from("Carol") };
+
+    assert_eq!(unsaved.get_id(), None);
+    assert_eq!(saved.get_id(), Some(0));
+    assert_eq!(SentinelId::INSERT_COLUMNS, &["name"]);
+    assert_eq!(
+        sqlx::Execute::sql(&unsaved.insert_query()),
+        "INSERT INTO users (name) VALUES (?)"
+    );
+}
@@ -0,0 +1,126 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id)]
+    id: i64,
+    #[model(column = "user_name")]
+    name: String,
+    #[model(skip_update)]
+    created_by: String,
+    #[model(skip_insert)]
+    updated_at: String,
+}
+
+#[derive(Model, FromRow)]
+#[model(table = "posts")]
+pub struct Post {
+    id: i64,
+    #[model(column = "post_title")]
+    #[sqlx(rename = "headline")]
+    title: String,
+    #[sqlx(rename = "body")]
+    content: String,
+}
+
+#[derive(FromRow)]
+pub struct Audit {
+    created_by: String,
+}
+
+// Raw identifiers are unquoted and fields without a column of their own are left out
+#[derive(Model, FromRow)]
+#[model(table = "items")]
+pub struct Item {
+    id: i64,
+    r#type: String,
+    #[sqlx(skip)]
+    cached: Vec<String>,
+    #[sqlx(flatten)]
+    audit: Audit,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+repository! {
+    !zst !crud
+    pub UserRepo2<User>;
+}
+
+fn main() {
+    assert_eq!(Item::COLUMNS, &["id", "type"]);
+    assert_eq!(Item::INSERT_COLUMNS, &["id", "type"]);
+    assert_eq!(Item::SELECT_COLUMNS, "id, type");
+
+    let item = Item {
+        id: 1,
+        r#type: String::from("book"),
+        cached: Vec::new(),
+        audit: Audit { created_by: String::from("system") },
+    };
+
+    assert_eq!(
+        sqlx::Execute::sql(&item.insert_query()),
+        "INSERT INTO items (id, type) VALUES (?, ?)"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&item.update_query()),
+        "UPDATE items SET type = ? WHERE id = ?"
+    );
+    let _ = (&item.cached, &item.audit.created_by);
+
+    let user = User {
+        id: 1,
+        name: String::from("Alice"),
+        created_by: String::from("system"),
+        updated_at: String::new(),
+    };
+
+    assert_eq!(User::TABLE_NAME, "users");
+    assert_eq!(User::ID_COLUMNS, &["id"]);
+    assert_eq!(User::COLUMNS, &["id", "user_name", "created_by", "updated_at"]);
+    assert_eq!(User::UPDATE_COLUMNS, &["user_name", "updated_at"]);
+    assert_eq!(
+        User::SELECT_COLUMNS,
+        "id, user_name AS name, created_by, updated_at"
+    );
+    assert_eq!(
+        User::SELECT_ALL_QUERY,
+        "SELECT id, user_name AS name, created_by, updated_at FROM users"
+    );
+    assert_eq!(
+        User::SELECT_BY_ID_QUERY,
+        "SELECT id, user_name AS name, created_by, updated_at FROM users WHERE id = ?"
+    );
+
+    assert_eq!(
+        sqlx::Execute::sql(&user.insert_query()),
+        "INSERT INTO users (id, user_name, created_by) VALUES (?, ?, ?)"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&user.update_query()),
+        "UPDATE users SET user_name = ?, updated_at = ? WHERE id = ?"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&User::delete_by_id_query(&1)),
+        "DELETE FROM users WHERE id = ?"
+    );
+    assert_eq!(
+        sqlx::Execute::sql(&User::get_by_id_query(1)),
+        "SELECT id, user_name AS name, created_by, updated_at FROM users WHERE id = ?"
+    );
+
+    assert_eq!(
+        sqlx::Execute::sql(&User::get_all_query()),
+        "SELECT id, user_name AS name, created_by, updated_at FROM users"
+    );
+    assert_eq!(
+        Post::SELECT_ALL_QUERY,
+        "SELECT id, post_title AS headline, content AS body FROM posts"
+    );
+}
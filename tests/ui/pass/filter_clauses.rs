@@ -0,0 +1,116 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+#[derive(Model, FromRow)]
+#[model(table = "customers")]
+pub struct Customer {
+    id: i64,
+    orders: i64,
+}
+
+repository! {
+    !crud
+    pub CustomerRepo<Customer>;
+}
+
+sql_filter! {
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+        ORDER BY ?name
+        ?LIMIT u32
+        ?OFFSET u32
+    }
+}
+
+sql_filter! {
+    pub struct CustomerFilter<CustomerRepo> {
+        SELECT c.id, COUNT(o.id) as orders FROM customers c
+        LEFT JOIN orders o ON o.customer_id = c.id
+        WHERE
+        ?c.country = String
+        GROUP BY c.id
+        HAVING ?COUNT(o.id) as min_orders >= i64 AND ?SUM(o.total) < f64
+        LIMIT u32
+    }
+}
+
+fn filter_sql(filter: UserFilter) -> String {
+    <UserRepo as FilterRepository<User>>::prepare_filter_query(filter).into_sql()
+}
+
+fn customer_sql(filter: CustomerFilter) -> String {
+    <CustomerRepo as FilterRepository<Customer>>::prepare_filter_query(filter).into_sql()
+}
+
+fn main() {
+    assert_eq!(
+        filter_sql(UserFilter::new().name("A%").limit(10u32).offset(20u32)),
+        "SELECT * FROM users WHERE name LIKE ? LIMIT ? OFFSET ?"
+    );
+    assert_eq!(
+        filter_sql(
+            UserFilter::new()
+                .limit(10u32)
+                .order_by(UserSort::Name, Direction::Asc)
+        ),
+        "SELECT * FROM users ORDER BY name ASC LIMIT ?"
+    );
+    assert_eq!(filter_sql(UserFilter::new()), "SELECT * FROM users");
+
+    // An offset on its own still gets a limit, as MySQL and SQLite require one
+    assert_eq!(
+        filter_sql(UserFilter::new().offset(5u32)),
+        "SELECT * FROM users LIMIT ? OFFSET ?"
+    );
+
+    let filter = UserFilter::new().limit(10u32).offset(20u32);
+    assert_eq!(filter.row_limit(), Some(10));
+    assert_eq!(filter.row_offset(), Some(20));
+    assert!(!filter.should_apply_filter());
+    assert!(!filter.groups_rows());
+
+    assert_eq!(
+        customer_sql(CustomerFilter::new(5u32).c_country("SE").min_orders(3)),
+        "SELECT c.id, COUNT(o.id) as orders FROM customers c \
+         LEFT JOIN orders o ON o.customer_id = c.id \
+         WHERE c.country = ? GROUP BY c.id HAVING COUNT(o.id) >= ? LIMIT ?"
+    );
+    assert_eq!(
+        customer_sql(CustomerFilter::new(5u32).min_orders(3).sum_o_total(100.0)),
+        "SELECT c.id, COUNT(o.id) as orders FROM customers c \
+         LEFT JOIN orders o ON o.customer_id = c.id \
+         GROUP BY c.id HAVING (COUNT(o.id) >= ? AND SUM(o.total) < ?) LIMIT ?"
+    );
+    assert!(CustomerFilter::new(5u32).groups_rows());
+
+    // Grouped or limited filters are counted in a subquery
+    let count = <CustomerRepo as FilterRepository<Customer>>::prepare_count_query(
+        CustomerFilter::new(5u32).c_country("SE"),
+    )
+    .unwrap();
+    assert_eq!(
+        count.sql(),
+        "SELECT COUNT(*) FROM (SELECT c.id, COUNT(o.id) as orders FROM customers c \
+         LEFT JOIN orders o ON o.customer_id = c.id \
+         WHERE c.country = ? GROUP BY c.id LIMIT ?) AS total"
+    );
+
+    let count = <UserRepo as FilterRepository<User>>::prepare_count_query(
+        UserFilter::new().name("A%"),
+    )
+    .unwrap();
+    assert_eq!(count.sql(), "SELECT COUNT(*) FROM users WHERE name LIKE ?");
+}
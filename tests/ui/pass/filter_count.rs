@@ -0,0 +1,75 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+sql_filter! {
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+    }
+}
+
+repository! {
+    pub GroupedUserRepo<User>;
+}
+
+impl FilterRepository<User> for GroupedUserRepo {
+    fn filter_query_builder<'args>() -> QueryBuilder<'args, Database> {
+        QueryBuilder::new("SELECT name FROM users")
+    }
+
+    fn post_filter_query(mut builder: QueryBuilder<Database>) -> QueryBuilder<Database> {
+        builder.push(" GROUP BY name");
+        builder
+    }
+}
+
+#[allow(dead_code)]
+async fn counts(repo: &UserRepo) -> sqlx_utils::Result<()> {
+    let total = repo.count_all().await?;
+    let named = repo.count_by_filter(UserFilter::new().name("A%")).await?;
+    assert!(named <= total);
+
+    if repo.exists_by_filter(UserFilter::new().name("A%")).await? {
+        assert!(repo.exists_by_id(1).await? || named > 0);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let count = <UserRepo as FilterRepository<User>>::prepare_count_query(
+        UserFilter::new().name("A%"),
+    )
+    .unwrap();
+    assert_eq!(count.sql(), "SELECT COUNT(*) FROM users WHERE name LIKE ?");
+
+    let exists = <UserRepo as FilterRepository<User>>::prepare_exists_query(
+        UserFilter::new().name("A%"),
+    )
+    .unwrap();
+    assert_eq!(
+        exists.sql(),
+        "SELECT EXISTS(SELECT 1 FROM users WHERE name LIKE ?)"
+    );
+
+    let grouped = <GroupedUserRepo as FilterRepository<User>>::prepare_count_query(
+        UserFilter::new().name("A%"),
+    )
+    .unwrap();
+    assert_eq!(
+        grouped.sql(),
+        "SELECT COUNT(*) FROM (SELECT name FROM users WHERE name LIKE ? GROUP BY name) AS total"
+    );
+}
@@ -0,0 +1,66 @@
+use sqlx_utils::prelude::*;
+
+fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+    let mut builder = QueryBuilder::new("");
+    filter.apply_filter(&mut builder);
+    builder.into_sql()
+}
+
+sql_filter! {
+    pub struct OrFirstFilter {
+        SELECT * FROM users WHERE
+        a = i32 OR b = i32 AND c = i32
+    }
+}
+
+sql_filter! {
+    pub struct AndFirstFilter {
+        SELECT * FROM users WHERE
+        a = i32 AND b = i32 OR c = i32
+    }
+}
+
+sql_filter! {
+    pub struct GroupFilter {
+        SELECT * FROM users WHERE
+        (a = i32 OR b = i32) AND c = i32
+    }
+}
+
+sql_filter! {
+    pub struct NotFilter {
+        SELECT * FROM users WHERE
+        NOT (a = i32 OR b = i32) AND NOT c = i32
+    }
+}
+
+sql_filter! {
+    pub struct OptionalFilter {
+        SELECT * FROM users WHERE
+        (?a = i32 OR ?b = i32) AND ?c = i32
+    }
+}
+
+fn main() {
+    assert_eq!(
+        render(OrFirstFilter::new(1, 2, 3)),
+        "(a = ? OR (b = ? AND c = ?))"
+    );
+    assert_eq!(
+        render(AndFirstFilter::new(1, 2, 3)),
+        "((a = ? AND b = ?) OR c = ?)"
+    );
+    assert_eq!(
+        render(GroupFilter::new(1, 2, 3)),
+        "((a = ? OR b = ?) AND c = ?)"
+    );
+    assert_eq!(
+        render(NotFilter::new(1, 2, 3)),
+        "(NOT ((a = ? OR b = ?)) AND NOT (c = ?))"
+    );
+    assert_eq!(
+        render(OptionalFilter::new().a(1).b(2)),
+        "(a = ? OR b = ?)"
+    );
+    assert_eq!(render(OptionalFilter::new().b(2).c(3)), "(b = ? AND c = ?)");
+}
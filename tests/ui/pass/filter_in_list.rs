@@ -0,0 +1,62 @@
+use sqlx_utils::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Status(i32);
+
+impl From<Status> for i32 {
+    fn from(status: Status) -> Self {
+        status.0
+    }
+}
+
+sql_filter! {
+    pub struct OrderFilter {
+        SELECT * FROM orders WHERE
+        ?status IN Vec<i32> AND
+        ?id NOT IN i64 AND
+        ?state IN "('open', 'paid')"
+    }
+}
+
+sql_filter! {
+    pub struct CustomerFilter {
+        SELECT * FROM customers WHERE
+        id IN Vec<i64>
+    }
+}
+
+fn main() {
+    let rendered = OrderFilter::new()
+        .status([Status(1), Status(2)])
+        .id(vec![7])
+        .render();
+
+    assert_eq!(
+        rendered.sql,
+        "((status IN (?, ?) AND id NOT IN (?)) AND state IN ('open', 'paid'))"
+    );
+    assert_eq!(rendered.arguments, ["1", "2", "7"]);
+
+    // An empty list is written as a condition instead of being skipped
+    let rendered = OrderFilter::new()
+        .status(Vec::<i32>::new())
+        .id(Vec::<i64>::new())
+        .render();
+
+    assert_eq!(
+        rendered.sql,
+        "((1 = 0 AND 1 = 1) AND state IN ('open', 'paid'))"
+    );
+
+    assert_eq!(
+        OrderFilter::new().render().sql,
+        "state IN ('open', 'paid')"
+    );
+
+    let ids: Vec<i64> = vec![1, 2, 3];
+    let filter = CustomerFilter::new(ids.iter().copied());
+
+    assert!(filter.should_apply_filter());
+    assert_eq!(filter.render().sql, "id IN (?, ?, ?)");
+    assert_eq!(CustomerFilter::new([0i64; 0]).render().sql, "1 = 0");
+}
@@ -0,0 +1,63 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "orders")]
+pub struct Order {
+    id: i64,
+    total: f64,
+}
+
+repository! {
+    !crud
+    pub OrderRepo<Order>;
+}
+
+sql_filter! {
+    pub struct OrderFilter<OrderRepo> {
+        SELECT o.*, c.name as customer_name FROM orders o
+        INNER JOIN customers c ON c.id = o.customer_id
+        LEFT OUTER JOIN shipments AS s ON s.order_id = o.id AND s.state != "'cancelled'"
+        WHERE
+        ?o.id = i64 AND
+        ?c.id = i64 AND
+        ?c.country = String AND
+        ?s.shipped_at IS NOT NULL AND
+        o.total >= f64
+        ORDER BY ?o.created_at, ?c.name as Customer
+    }
+}
+
+fn main() {
+    let filter = OrderFilter::new(10.0)
+        .o_id(1)
+        .c_id(2)
+        .c_country("SE")
+        .s_shipped_at(true)
+        .order_by(OrderSort::OCreatedAt, Direction::Desc)
+        .order_by(OrderSort::Customer, Direction::Asc);
+
+    let query = <OrderRepo as FilterRepository<Order>>::prepare_filter_query(filter);
+    assert_eq!(
+        query.sql(),
+        "SELECT o.*, c.name as customer_name FROM orders o \
+         INNER JOIN customers c ON c.id = o.customer_id \
+         LEFT JOIN shipments s ON s.order_id = o.id AND s.state != 'cancelled' \
+         WHERE ((((o.id = ? AND c.id = ?) AND c.country = ?) AND s.shipped_at IS NOT NULL) AND o.total >= ?) \
+         ORDER BY o.created_at DESC, c.name ASC"
+    );
+
+    assert_eq!(OrderSort::OCreatedAt.column(), "o.created_at");
+
+    let count = <OrderRepo as FilterRepository<Order>>::prepare_count_query(
+        OrderFilter::new(10.0).c_country("SE"),
+    )
+    .unwrap();
+    assert_eq!(
+        count.sql(),
+        "SELECT COUNT(*) FROM orders o \
+         INNER JOIN customers c ON c.id = o.customer_id \
+         LEFT JOIN shipments s ON s.order_id = o.id AND s.state != 'cancelled' \
+         WHERE (c.country = ? AND o.total >= ?)"
+    );
+}
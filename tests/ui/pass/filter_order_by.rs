@@ -0,0 +1,73 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    name: String,
+    created_at: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+sql_filter! {
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+        ORDER BY ?name, ?created_at
+    }
+}
+
+repository! {
+    pub GroupedUserRepo<User>;
+}
+
+impl FilterRepository<User> for GroupedUserRepo {
+    fn filter_query_builder<'args>() -> QueryBuilder<'args, Database> {
+        QueryBuilder::new("SELECT name, created_at FROM users")
+    }
+
+    fn post_filter_query(mut builder: QueryBuilder<Database>) -> QueryBuilder<Database> {
+        builder.push(" GROUP BY name, created_at");
+        builder
+    }
+}
+
+fn main() {
+    assert_eq!(UserSort::CreatedAt.column(), "created_at");
+
+    let query = <UserRepo as FilterRepository<User>>::prepare_filter_query(
+        UserFilter::new()
+            .name("A%")
+            .order_by(UserSort::CreatedAt, Direction::Desc)
+            .order_by(UserSort::Name, Direction::Asc),
+    );
+    assert_eq!(
+        query.sql(),
+        "SELECT * FROM users WHERE name LIKE ? ORDER BY created_at DESC, name ASC"
+    );
+
+    let unfiltered =
+        <UserRepo as FilterRepository<User>>::prepare_filter_query(UserFilter::new());
+    assert_eq!(unfiltered.sql(), "SELECT * FROM users");
+
+    let count = <UserRepo as FilterRepository<User>>::prepare_count_query(
+        UserFilter::new().order_by(UserSort::Name, Direction::Desc),
+    )
+    .unwrap();
+    assert_eq!(count.sql(), "SELECT COUNT(*) FROM users");
+
+    let grouped = <GroupedUserRepo as FilterRepository<User>>::prepare_filter_query(
+        UserFilter::new()
+            .name("A%")
+            .order_by(UserSort::Name, Direction::Asc),
+    );
+    assert_eq!(
+        grouped.sql(),
+        "SELECT name, created_at FROM users WHERE name LIKE ? GROUP BY name, created_at ORDER BY name ASC"
+    );
+}
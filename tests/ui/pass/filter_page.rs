@@ -0,0 +1,154 @@
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+sql_filter! {
+    #[derive(Clone)]
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+    }
+}
+
+/// Walks every page of `request`, returning the ids in the order they were read.
+async fn walk(repo: &UserRepo, filter: UserFilter, request: PageRequest) -> Vec<i64> {
+    let mut page = repo.get_page_by_filter(filter.clone(), request).await.unwrap();
+    let total = page.total;
+    let mut ids = Vec::new();
+
+    loop {
+        assert_eq!(page.total, total);
+        assert!(page.items.len() <= 5);
+        ids.extend(page.items.iter().map(|user| user.id));
+
+        match page.next {
+            Some(next) => page = repo.get_page_by_filter(filter.clone(), next).await.unwrap(),
+            None => break,
+        }
+    }
+
+    assert_eq!(ids.len() as u64, total);
+    ids
+}
+
+async fn pages(repo: &UserRepo) {
+    // 15 of 20 users start with `A`, with every name shared by several ids
+    let mut expected = Vec::new();
+
+    for id in 1..=20 {
+        let name = match id % 4 {
+            0 => "Anna",
+            1 => "Adam",
+            2 => "Bob",
+            _ => "Alice",
+        };
+
+        sqlx::query("INSERT INTO users (id, name) VALUES (?, ?)")
+            .bind(id)
+            .bind(name)
+            .execute(repo.pool())
+            .await
+            .unwrap();
+
+        if name.starts_with('A') {
+            expected.push((name, id));
+        }
+    }
+
+    let filter = UserFilter::new().name("A%");
+
+    // By id, ascending and descending
+    let ids = walk(repo, filter.clone(), PageRequest::keyset(5, &["id"])).await;
+    let by_id = expected.iter().map(|(_, id)| *id).collect::<Vec<_>>();
+    assert_eq!(ids, by_id);
+
+    let ids = walk(
+        repo,
+        filter.clone(),
+        PageRequest::keyset(5, &["id"]).direction(Direction::Desc),
+    )
+    .await;
+    assert_eq!(ids, by_id.iter().rev().copied().collect::<Vec<_>>());
+
+    // By name with the id breaking ties, which crosses pages within the same name
+    let ids = walk(repo, filter.clone(), PageRequest::keyset(5, &["name", "id"])).await;
+    expected.sort();
+    assert_eq!(ids, expected.iter().map(|(_, id)| *id).collect::<Vec<_>>());
+
+    // A page size dividing the rows evenly still ends without an empty page
+    let mut page = repo
+        .get_page_by_filter(filter.clone(), PageRequest::keyset(15, &["id"]))
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 15);
+    assert!(page.next.is_none());
+
+    page = repo
+        .get_page_by_filter(filter.clone(), PageRequest::keyset(3, &["id"]))
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 3);
+    assert!(page.next.is_some());
+
+    let offset: Page<User> = repo
+        .get_page_by_any_filter(filter, PageRequest::offset(5, 10).order_by(&["id"], Direction::Asc))
+        .await
+        .unwrap();
+    assert_eq!(offset.total, 15);
+    assert_eq!(
+        offset.items.iter().map(|user| user.id).collect::<Vec<_>>(),
+        by_id[10..]
+    );
+    assert!(offset.next.is_none());
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    initialize_db_pool(pool);
+
+    pages(&UserRepo::new()).await;
+
+    let count = <UserRepo as FilterRepository<User>>::prepare_count_query(
+        UserFilter::new().name("A%"),
+    )
+    .unwrap();
+    assert_eq!(
+        count.sql(),
+        "SELECT COUNT(*) FROM users WHERE name LIKE ?"
+    );
+
+    let page = <UserRepo as FilterRepository<User>>::prepare_page_query(
+        UserFilter::new(),
+        &PageRequest::offset(10, 20).order_by(&["name"], Direction::Asc),
+    )
+    .unwrap();
+    assert_eq!(
+        page.sql(),
+        "SELECT * FROM (SELECT * FROM users) AS page ORDER BY name ASC LIMIT 11 OFFSET 20"
+    );
+}
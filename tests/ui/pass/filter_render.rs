@@ -0,0 +1,24 @@
+use sqlx_utils::prelude::*;
+
+sql_filter! {
+    pub struct UserFilter {
+        SELECT * FROM users WHERE
+        ?id = i64 AND
+        ?name LIKE String AND
+        deleted_at IS NULL
+    }
+}
+
+fn main() {
+    let rendered = UserFilter::new().id(42).name("Alice%").render();
+
+    assert_eq!(
+        rendered.sql,
+        "((id = ? AND name LIKE ?) AND deleted_at IS NULL)"
+    );
+    assert_eq!(rendered.arguments, ["42", "\"Alice%\""]);
+    assert_eq!(
+        UserFilter::new().render().to_string(),
+        "deleted_at IS NULL []"
+    );
+}
@@ -0,0 +1,66 @@
+use sqlx_utils::prelude::*;
+
+#[allow(dead_code)]
+fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+    let mut builder = QueryBuilder::new("");
+    filter.apply_filter(&mut builder);
+    builder.into_sql()
+}
+
+// Filters without the derive never implement `Deserialize`, whatever the enabled features
+sql_filter! {
+    pub struct PlainFilter {
+        SELECT * FROM users WHERE
+        ?name = String
+    }
+}
+
+#[cfg(feature = "serde")]
+mod deserialize {
+    use super::render;
+    use sqlx_utils::prelude::*;
+    use sqlx_utils::serde::Deserialize;
+
+    sql_filter! {
+        #[derive(Deserialize)]
+        pub struct UserFilter {
+            SELECT * FROM users WHERE
+            ?name = String AND
+            ?age >= i32 AND
+            ?age as max_age <= i32 AND
+            ?deleted_at IS NULL AND
+            ?created BETWEEN i64 AND i64
+            ORDER BY ?name
+        }
+    }
+
+    pub fn deserialize() {
+        // Keys that are not part of the filter, like `page`, are ignored
+        let filter: UserFilter =
+            serde_urlencoded::from_str("name=foo&age_gte=18&max_age=65&page=2").unwrap();
+
+        assert_eq!(
+            render(filter),
+            "((name = ? AND age >= ?) AND age <= ?)"
+        );
+
+        let filter: UserFilter = serde_urlencoded::from_str("deleted_at_is_null=false").unwrap();
+
+        assert_eq!(render(filter), "deleted_at IS NOT NULL");
+
+        let filter: UserFilter = serde_json::from_str(r#"{"created_between": [1, 2]}"#).unwrap();
+
+        assert_eq!(render(filter), "created BETWEEN ? AND ?");
+
+        let filter: UserFilter = serde_urlencoded::from_str("").unwrap();
+
+        assert!(!filter.should_apply_filter());
+    }
+}
+
+fn main() {
+    assert_eq!(render(PlainFilter::new().name("foo")), "name = ?");
+
+    #[cfg(feature = "serde")]
+    deserialize::deserialize();
+}
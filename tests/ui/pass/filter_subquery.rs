@@ -0,0 +1,53 @@
+use sqlx_utils::filter::{Filter, exists, in_subquery};
+use sqlx_utils::prelude::*;
+
+sql_filter! {
+    pub struct CustomerFilter {
+        SELECT * FROM customers c WHERE
+        ?c.country = String
+    }
+}
+
+sql_filter! {
+    pub struct BigOrderFilter {
+        SELECT customer_id FROM orders WHERE
+        ?total >= f64 AND
+        ?state = String
+        ?LIMIT u32
+    }
+}
+
+sql_filter! {
+    pub struct OpenOrderFilter {
+        SELECT 1 FROM orders o WHERE
+        o.customer_id = "c.id" AND
+        o.state = "'open'"
+    }
+}
+
+fn main() {
+    assert_eq!(
+        <BigOrderFilter as SelectFilter>::QUERY,
+        "SELECT customer_id FROM orders"
+    );
+
+    let rendered = Filter::new(CustomerFilter::new().c_country("SE"))
+        .and(in_subquery(
+            "c.id",
+            BigOrderFilter::new().total(100.0).state("paid").limit(10u32),
+        ))
+        .render();
+
+    assert_eq!(
+        rendered.sql,
+        "(c.country = ? AND c.id IN (SELECT customer_id FROM orders WHERE (total >= ? AND state = ?) LIMIT ?))"
+    );
+    assert_eq!(rendered.arguments, ["\"SE\"", "100.0", "\"paid\"", "10"]);
+
+    let rendered = exists(OpenOrderFilter::new()).not().render();
+
+    assert_eq!(
+        rendered.sql,
+        "NOT (EXISTS (SELECT 1 FROM orders o WHERE (o.customer_id = c.id AND o.state = 'open')))"
+    );
+}
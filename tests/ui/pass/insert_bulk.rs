@@ -0,0 +1,40 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id, generated)]
+    id: i64,
+    name: String,
+    #[model(column = "mail")]
+    email: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+#[allow(dead_code)]
+async fn insert(repo: &UserRepo, users: Vec<User>) -> sqlx_utils::Result<()> {
+    let mut tx = repo.pool().begin().await?;
+    repo.insert_bulk_with_executor(&mut *tx, &users).await?;
+    tx.commit().await?;
+
+    repo.insert_bulk_batch::<512, _>(users).await
+}
+
+fn main() {
+    let users = vec![
+        User { id: 0, name: String::from("Alice"), email: String::from("alice@example.com") },
+        User { id: 0, name: String::from("Bob"), email: String::from("bob@example.com") },
+        User { id: 0, name: String::from("Carol"), email: String::from("carol@example.com") },
+    ];
+
+    assert_eq!(User::INSERT_COLUMNS, &["name", "mail"]);
+    assert_eq!(
+        User::insert_many_query(&users).sql(),
+        "INSERT INTO users (name, mail) VALUES (?, ?), (?, ?), (?, ?)"
+    );
+}
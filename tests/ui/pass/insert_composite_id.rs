@@ -0,0 +1,62 @@
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+use sqlx_utils::types::PoolOptions;
+
+#[derive(Model, FromRow, Debug, PartialEq)]
+#[model(table = "user_roles")]
+pub struct UserRole {
+    #[model(id)]
+    user_id: i64,
+    #[model(id, column = "role")]
+    role_id: i64,
+    granted_by: String,
+}
+
+repository! {
+    !crud
+    pub UserRoleRepo<UserRole>;
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE user_roles (user_id INTEGER, role INTEGER, granted_by TEXT, \
+         PRIMARY KEY (user_id, role))",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    initialize_db_pool(pool);
+
+    let role = UserRole {
+        user_id: 1,
+        role_id: 2,
+        granted_by: String::from("admin"),
+    };
+
+    assert!(role.has_id());
+    assert!(role.is_new());
+
+    let repo = UserRoleRepo::new();
+
+    repo.insert_ref(&role).await.unwrap();
+    repo.insert_bulk(vec![
+        UserRole { user_id: 1, role_id: 3, granted_by: String::from("admin") },
+        UserRole { user_id: 2, role_id: 2, granted_by: String::from("admin") },
+    ])
+    .await
+    .unwrap();
+
+    assert_eq!(repo.get_by_id((1, 2)).await.unwrap(), Some(role));
+    assert_eq!(repo.get_all().await.unwrap().len(), 3);
+}
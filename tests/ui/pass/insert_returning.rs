@@ -0,0 +1,82 @@
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id, generated)]
+    id: i64,
+    name: String,
+}
+
+fn user(name: &str) -> User {
+    User { id: 0, name: name.to_string() }
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+async fn name_of(repo: &UserRepo, id: i64) -> Option<String> {
+    repo.get_by_id(id).await.unwrap().map(|user| user.name)
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    initialize_db_pool(pool);
+
+    let repo = UserRepo::new();
+
+    // The generated id is read back
+    let alice = repo.insert_returning(user("Alice")).await.unwrap();
+    assert_eq!(alice.id, 1);
+    assert_eq!(alice.name, "Alice");
+    assert_eq!(name_of(&repo, alice.id).await.as_deref(), Some("Alice"));
+
+    let mut tx = repo.pool().begin().await.unwrap();
+    let bob = repo
+        .insert_returning_with_executor(&mut *tx, user("Bob"))
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    assert_eq!(bob.id, 2);
+    assert_eq!(name_of(&repo, bob.id).await.as_deref(), Some("Bob"));
+
+    // Every model gets its own id, in the order they were given
+    let users = repo
+        .insert_many_returning(["Carol", "Dave", "Eve"].map(user))
+        .await
+        .unwrap();
+    assert_eq!(
+        users.iter().map(|user| (user.id, user.name.as_str())).collect::<Vec<_>>(),
+        [(3, "Carol"), (4, "Dave"), (5, "Eve")]
+    );
+
+    for user in &users {
+        assert_eq!(name_of(&repo, user.id).await.as_ref(), Some(&user.name));
+    }
+
+    // Batches of a fixed size read back the same way
+    let users = repo
+        .insert_batch_returning::<2, _>(["Frank", "Grace", "Heidi"].map(user))
+        .await
+        .unwrap();
+    assert_eq!(users.iter().map(|user| user.id).collect::<Vec<_>>(), [6, 7, 8]);
+
+    assert!(repo.insert_many_returning(Vec::new()).await.unwrap().is_empty());
+}
@@ -0,0 +1,46 @@
+use sqlx_utils::prelude::*;
+
+fn render<'args>(filter: impl SqlFilter<'args>) -> String {
+    let mut builder = QueryBuilder::new("");
+    filter.apply_filter(&mut builder);
+    builder.into_sql()
+}
+
+sql_filter! {
+    pub struct UserFilter {
+        SELECT * FROM users WHERE
+        deleted_at IS NULL AND
+        ?archived IS NOT NULL AND
+        ?created BETWEEN i64 AND i64 AND
+        ?name LIKE String
+    }
+}
+
+sql_filter! {
+    pub struct RequiredFilter {
+        SELECT * FROM users WHERE
+        age BETWEEN i32 AND i32 OR manager_id is not null
+    }
+}
+
+fn main() {
+    assert!(UserFilter::new().should_apply_filter());
+    assert_eq!(render(UserFilter::new()), "deleted_at IS NULL");
+    assert_eq!(
+        render(UserFilter::new().archived(true)),
+        "(deleted_at IS NULL AND archived IS NOT NULL)"
+    );
+    assert_eq!(
+        render(UserFilter::new().archived(false).created((1, 2))),
+        "((deleted_at IS NULL AND archived IS NULL) AND created BETWEEN ? AND ?)"
+    );
+    assert_eq!(
+        render(UserFilter::new().created((1, 2)).name("A%")),
+        "((deleted_at IS NULL AND created BETWEEN ? AND ?) AND name LIKE ?)"
+    );
+
+    assert_eq!(
+        render(RequiredFilter::new((18, 65))),
+        "(age BETWEEN ? AND ? OR manager_id IS NOT NULL)"
+    );
+}
@@ -0,0 +1,106 @@
+use futures::{StreamExt, TryStreamExt};
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+sql_filter! {
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+    }
+}
+
+fn names(users: &[User]) -> Vec<&str> {
+    users.iter().map(|user| user.name.as_str()).collect()
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    for (id, name) in ["Alice", "Bob", "Anna", "Carol", "Adam"].into_iter().enumerate() {
+        sqlx::query("INSERT INTO users (id, name) VALUES (?, ?)")
+            .bind(id as i64 + 1)
+            .bind(name)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    initialize_db_pool(pool);
+
+    let repo = UserRepo::new();
+
+    let all: Vec<User> = repo.stream_all().try_collect().await.unwrap();
+    assert_eq!(names(&all), ["Alice", "Bob", "Anna", "Carol", "Adam"]);
+    assert_eq!(all.iter().map(|user| user.id).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+    let filtered: Vec<User> = repo
+        .stream_by_filter(UserFilter::new().name("A%"))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(names(&filtered), ["Alice", "Anna", "Adam"]);
+
+    // Dropping a stream early gives the only connection back to the pool
+    {
+        let mut users = std::pin::pin!(repo.stream_all());
+        let first = users.try_next().await.unwrap().unwrap();
+        assert_eq!(first.name, "Alice");
+    }
+
+    let first_two: Vec<User> = repo
+        .stream_by_filter(UserFilter::new().name("A%"))
+        .take(2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(names(&first_two), ["Alice", "Anna"]);
+
+    assert_eq!(repo.count_all().await.unwrap(), 5);
+
+    // A stream over a transaction only sees what the transaction sees
+    let mut tx = repo.pool().begin().await.unwrap();
+    sqlx::query("DELETE FROM users WHERE name = 'Anna'")
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+
+    let filtered: Vec<User> = repo
+        .stream_by_filter_with_executor(&mut *tx, UserFilter::new().name("A%"))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(names(&filtered), ["Alice", "Adam"]);
+    tx.rollback().await.unwrap();
+
+    let empty: Vec<User> = repo
+        .stream_by_filter(UserFilter::new().name("Z%"))
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(empty.is_empty());
+}
@@ -0,0 +1,131 @@
+use futures::TryStreamExt;
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id, generated)]
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+sql_filter! {
+    #[derive(Clone)]
+    pub struct UserFilter<UserRepo> {
+        SELECT * FROM users WHERE
+        ?name LIKE String
+    }
+}
+
+async fn rename(repo: &UserRepo, from: &str, to: &str) -> sqlx_utils::Result<u64> {
+    let mut tx = repo.pool().begin().await?;
+    let mut users = repo.in_tx(&mut tx);
+
+    let renamed = users
+        .get_all_by_filter(UserFilter::new().name(from))
+        .await?
+        .into_iter()
+        .map(|user| User { name: to.to_string(), ..user });
+
+    for user in renamed {
+        users.update_ref(&user).await?;
+    }
+
+    let user = users.insert_returning(User { id: 0, name: from.to_string() }).await?;
+    users.delete_by_id(user.id).await?;
+
+    {
+        let mut stream = std::pin::pin!(users.stream_by_any_filter(UserFilter::new()));
+        while let Some(user) = stream.try_next().await? {
+            drop(user);
+        }
+    }
+
+    let count = users.count_by_filter(UserFilter::new().name(to)).await?;
+
+    // Everything written above is read back before the transaction commits
+    let all = users.get_all().await?;
+    assert!(all.iter().all(|user| user.name == to));
+    assert_eq!(all.len() as u64, count);
+    assert!(users.get_by_id(user.id).await?.is_none());
+
+    let first = users.get_by_id(all[0].id).await?.expect("the user is renamed in the transaction");
+    assert_eq!(first.name, to);
+
+    tx.commit().await?;
+    Ok(count)
+}
+
+async fn in_transaction(repo: &UserRepo, user: User) -> sqlx_utils::Result<User> {
+    repo.with_transaction(|mut tx| async move {
+        let res = repo.in_tx(&mut tx).save(user).await;
+        (res, tx)
+    })
+    .await
+}
+
+async fn in_savepoint(repo: &UserRepo, user: User) -> sqlx_utils::Result<User> {
+    repo.with_transaction(|mut tx| async move {
+        let res = repo
+            .with_savepoint(&mut tx, |mut savepoint| async move {
+                let res = repo.in_tx(&mut savepoint).save(user).await;
+                (res, savepoint)
+            })
+            .await;
+        (res, tx)
+    })
+    .await
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    initialize_db_pool(pool);
+
+    let repo = UserRepo::new();
+
+    in_transaction(&repo, User { id: 0, name: "Alice".into() }).await.unwrap();
+    in_savepoint(&repo, User { id: 0, name: "Alice".into() }).await.unwrap();
+
+    assert_eq!(rename(&repo, "Alice", "Bob").await.unwrap(), 2);
+
+    let names: Vec<String> = sqlx::query_scalar("SELECT name FROM users ORDER BY id")
+        .fetch_all(repo.pool())
+        .await
+        .unwrap();
+    assert_eq!(names, ["Bob", "Bob"]);
+
+    // Writes are only visible inside the transaction until it commits
+    let mut tx = repo.pool().begin().await.unwrap();
+    let carol = repo
+        .in_tx(&mut tx)
+        .insert_returning(User { id: 0, name: "Carol".into() })
+        .await
+        .unwrap();
+    let read = repo.in_tx(&mut tx).get_by_id(carol.id).await.unwrap();
+    assert_eq!(read.map(|user| user.name).as_deref(), Some("Carol"));
+    assert_eq!(repo.in_tx(&mut tx).get_all().await.unwrap().len(), 3);
+    tx.rollback().await.unwrap();
+
+    assert!(repo.get_by_id(carol.id).await.unwrap().is_none());
+    assert_eq!(repo.get_all().await.unwrap().len(), 2);
+}
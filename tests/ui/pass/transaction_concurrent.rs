@@ -0,0 +1,108 @@
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+use std::sync::Mutex;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id, generated)]
+    id: i64,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+async fn insert_all(repo: &UserRepo, names: Vec<String>) -> sqlx_utils::Result<Vec<User>> {
+    repo.transaction_concurrent(names.into_iter().map(|name| {
+        move |tx: SharedTransaction<'static>| async move {
+            let mut tx = tx.lock().await?;
+
+            repo.insert_with_executor(&mut **tx, User { id: 0, name }).await
+        }
+    }))
+    .await
+}
+
+async fn names(repo: &UserRepo) -> Vec<String> {
+    sqlx::query_scalar("SELECT name FROM users ORDER BY name")
+        .fetch_all(repo.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    initialize_db_pool(pool);
+
+    let repo = UserRepo::new();
+
+    // Every action commits together
+    let users = insert_all(&repo, vec!["Alice".into(), "Bob".into(), "Carol".into()])
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 3);
+    assert_eq!(names(&repo).await, ["Alice", "Bob", "Carol"]);
+
+    // A failing action rolls back the inserts of the others and its own
+    let failed = repo
+        .transaction_concurrent((0..3).map(|i| {
+            let repo = &repo;
+
+            move |tx: SharedTransaction<'static>| async move {
+                let mut tx = tx.lock().await?;
+                let user = repo
+                    .insert_with_executor(&mut **tx, User { id: 0, name: format!("Dave {i}") })
+                    .await?;
+
+                if i == 2 {
+                    return Err(sqlx_utils::Error::Repository {
+                        message: "Dave 2 is not allowed".into(),
+                    });
+                }
+
+                Ok(user)
+            }
+        }))
+        .await;
+    assert!(failed.is_err());
+    assert_eq!(names(&repo).await, ["Alice", "Bob", "Carol"]);
+
+    // A handle kept past the end of the transaction can no longer lock it
+    let kept = Mutex::new(None);
+    let (kept_ref, repo_ref) = (&kept, &repo);
+
+    repo.transaction_concurrent([move |tx: SharedTransaction<'static>| async move {
+        *kept_ref.lock().unwrap() = Some(tx.clone());
+
+        let mut tx = tx.lock().await?;
+        repo_ref
+            .insert_with_executor(&mut **tx, User { id: 0, name: "Eve".into() })
+            .await
+    }])
+    .await
+    .unwrap();
+
+    let kept = kept.into_inner().unwrap().expect("the handle is kept by the action");
+    assert!(matches!(
+        kept.lock().await,
+        Err(sqlx_utils::Error::TransactionClosed)
+    ));
+    assert_eq!(names(&repo).await, ["Alice", "Bob", "Carol", "Eve"]);
+}
@@ -0,0 +1,146 @@
+use futures::future::{self, BoxFuture};
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+#[derive(Model, FromRow, Clone)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id, generated)]
+    id: Option<i64>,
+    name: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+#[derive(Debug)]
+enum SignupError {
+    Conflict,
+    Taken,
+    Database(sqlx_utils::Error),
+}
+
+impl From<sqlx_utils::Error> for SignupError {
+    fn from(error: sqlx_utils::Error) -> Self {
+        Self::Database(error)
+    }
+}
+
+impl From<sqlx::Error> for SignupError {
+    fn from(error: sqlx::Error) -> Self {
+        Self::Database(error.into())
+    }
+}
+
+impl RetryableError for SignupError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Conflict)
+    }
+}
+
+static SLEPT: AtomicU32 = AtomicU32::new(0);
+
+fn no_sleep(_: Duration) -> BoxFuture<'static, ()> {
+    SLEPT.fetch_add(1, Ordering::SeqCst);
+    Box::pin(future::ready(()))
+}
+
+/// Inserts `name` on every attempt, failing with `error` on the first `failures` attempts.
+async fn signup(
+    repo: &UserRepo,
+    policy: RetryPolicy,
+    name: &str,
+    failures: u32,
+    error: fn() -> SignupError,
+) -> (Result<(), SignupError>, u32) {
+    let attempts = AtomicU32::new(0);
+
+    let result = repo
+        .with_retrying_transaction(policy, |mut tx| {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            let user = User { id: None, name: name.to_string() };
+
+            async move {
+                let res = match repo.insert_with_executor(&mut *tx, user).await {
+                    Ok(_) if attempt <= failures => Err(error()),
+                    Ok(_) => Ok(()),
+                    Err(err) => Err(SignupError::from(err)),
+                };
+
+                (res, tx)
+            }
+        })
+        .await;
+
+    (result, attempts.load(Ordering::SeqCst))
+}
+
+async fn count(repo: &UserRepo, name: &str) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE name = ?")
+        .bind(name)
+        .fetch_one(repo.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    initialize_db_pool(pool);
+
+    let repo = UserRepo::new();
+    let policy = RetryPolicy::new(3).sleep(no_sleep);
+
+    // Fails twice, then commits on the third attempt with the earlier inserts rolled back
+    let (result, attempts) = signup(&repo, policy, "alice", 2, || SignupError::Conflict).await;
+
+    assert!(result.is_ok(), "{result:?}");
+    assert_eq!(attempts, 3);
+    assert_eq!(SLEPT.load(Ordering::SeqCst), 2);
+    assert_eq!(count(&repo, "alice").await, 1);
+
+    // Gives up after the last attempt
+    let (result, attempts) = signup(&repo, policy, "bob", 5, || SignupError::Conflict).await;
+
+    assert!(matches!(result, Err(SignupError::Conflict)));
+    assert_eq!(attempts, 3);
+    assert_eq!(count(&repo, "bob").await, 0);
+
+    // Other errors are not retried
+    let (result, attempts) = signup(&repo, policy, "carol", 1, || SignupError::Taken).await;
+
+    assert!(matches!(result, Err(SignupError::Taken)));
+    assert_eq!(attempts, 1);
+    assert_eq!(count(&repo, "carol").await, 0);
+
+    // The default sleep waits without a runtime timer
+    let (result, attempts) = signup(
+        &repo,
+        RetryPolicy::new(2).base_delay(Duration::from_millis(1)),
+        "dave",
+        1,
+        || SignupError::Conflict,
+    )
+    .await;
+
+    assert!(result.is_ok(), "{result:?}");
+    assert_eq!(attempts, 2);
+    assert_eq!(count(&repo, "dave").await, 1);
+}
@@ -0,0 +1,110 @@
+use sqlx_utils::pool::initialize_db_pool;
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::{FromRow, install_default_drivers};
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    #[model(id, generated)]
+    id: i64,
+    name: String,
+}
+
+fn user(name: &str) -> User {
+    User { id: 0, name: name.to_string() }
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+async fn names(repo: &UserRepo) -> Vec<String> {
+    sqlx::query_scalar("SELECT name FROM users ORDER BY id")
+        .fetch_all(repo.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::main]
+async fn main() {
+    install_default_drivers();
+
+    let pool = PoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    initialize_db_pool(pool);
+
+    let repo = UserRepo::new();
+
+    // A transaction of its own
+    repo.insert_in_transaction(user("Alice")).await.unwrap();
+
+    let mut tx = repo.pool().begin().await.unwrap();
+
+    // Savepoints inside the outer transaction
+    repo.insert_in_transaction_on(&mut tx, user("Bob")).await.unwrap();
+    repo.insert_in_transaction_on(&mut tx, user("Temp")).await.unwrap();
+    repo.delete_by_values_in_transaction_on(&mut tx, "id", [3]).await.unwrap();
+
+    // A failing savepoint only rolls back its own insert
+    let failed: sqlx_utils::Result<()> = repo
+        .with_savepoint(&mut tx, |mut savepoint| async move {
+            let res = repo.insert_with_executor(&mut *savepoint, user("Carol")).await;
+            assert!(res.is_ok());
+
+            let res = Err(sqlx_utils::Error::Repository {
+                message: "Carol is not allowed".into(),
+            });
+
+            (res, savepoint)
+        })
+        .await;
+    assert!(failed.is_err());
+
+    let saved = repo
+        .with_savepoint(&mut tx, |mut savepoint| async move {
+            let res = repo.insert_with_executor(&mut *savepoint, user("Dave")).await;
+
+            (res, savepoint)
+        })
+        .await;
+    assert!(saved.is_ok());
+
+    // Nothing is visible outside of the transaction before it commits
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut *tx)
+        .await
+        .unwrap();
+    assert_eq!(count, 3);
+
+    tx.commit().await.unwrap();
+
+    assert_eq!(names(&repo).await, ["Alice", "Bob", "Dave"]);
+
+    // Savepoints that succeeded are undone with the outer transaction
+    let mut tx = repo.pool().begin().await.unwrap();
+    repo.insert_in_transaction_on(&mut tx, user("Eve")).await.unwrap();
+    tx.rollback().await.unwrap();
+
+    // A transaction started on the pool itself
+    let saved = repo
+        .with_transaction_on(repo.pool(), |mut tx| async move {
+            let res = repo.save_with_executor(&mut *tx, user("Frank")).await;
+
+            (res, tx)
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(saved.name, "Frank");
+    assert_eq!(names(&repo).await, ["Alice", "Bob", "Dave", "Frank"]);
+}
@@ -0,0 +1,89 @@
+use sqlx_utils::prelude::*;
+use sqlx_utils::sqlx::FromRow;
+
+#[derive(Model, FromRow)]
+#[model(table = "users")]
+pub struct User {
+    id: i64,
+    email: String,
+    name: String,
+    #[model(skip_update)]
+    created_by: String,
+}
+
+repository! {
+    !crud
+    pub UserRepo<User>;
+}
+
+repository_upsert! {
+    UserRepo<User>;
+}
+
+repository! {
+    pub UserByEmailRepo<User>;
+}
+
+repository_upsert! {
+    UserByEmailRepo<User>;
+
+    const CONFLICT_COLUMNS: &'static [&'static str] = &["email"];
+    const UPDATE_COLUMNS: Option<&'static [&'static str]> = Some(&["name"]);
+}
+
+repository! {
+    pub UserIgnoreRepo<User>;
+}
+
+repository_upsert! {
+    UserIgnoreRepo<User>;
+
+    const UPDATE_COLUMNS: Option<&'static [&'static str]> = Some(&[]);
+}
+
+#[allow(dead_code)]
+async fn upsert(repo: &UserRepo, users: Vec<User>) -> sqlx_utils::Result<()> {
+    repo.upsert(User { id: 1, email: String::new(), name: String::new(), created_by: String::new() }).await?;
+    repo.upsert_batch::<128, _>(users).await
+}
+
+fn main() {
+    let users = vec![
+        User {
+            id: 1,
+            email: String::from("alice@example.com"),
+            name: String::from("Alice"),
+            created_by: String::from("system"),
+        },
+        User {
+            id: 2,
+            email: String::from("bob@example.com"),
+            name: String::from("Bob"),
+            created_by: String::from("system"),
+        },
+    ];
+
+    assert_eq!(
+        UserRepo::upsert_query(&users, Dialect::Postgres).sql(),
+        "INSERT INTO users (id, email, name, created_by) VALUES (?, ?, ?, ?), (?, ?, ?, ?) \
+         ON CONFLICT (id) DO UPDATE SET email = EXCLUDED.email, name = EXCLUDED.name"
+    );
+    assert_eq!(
+        UserRepo::upsert_query(&users[..1], Dialect::MySql).sql(),
+        "INSERT INTO users (id, email, name, created_by) VALUES (?, ?, ?, ?) \
+         ON DUPLICATE KEY UPDATE email = VALUES(email), name = VALUES(name)"
+    );
+    assert_eq!(
+        UserByEmailRepo::upsert_query(&users[..1], Dialect::Sqlite).sql(),
+        "INSERT INTO users (id, email, name, created_by) VALUES (?, ?, ?, ?) \
+         ON CONFLICT (email) DO UPDATE SET name = EXCLUDED.name"
+    );
+    assert_eq!(
+        UserIgnoreRepo::upsert_query(&users[..1], Dialect::Postgres).sql(),
+        "INSERT INTO users (id, email, name, created_by) VALUES (?, ?, ?, ?) ON CONFLICT (id) DO NOTHING"
+    );
+    assert_eq!(
+        UserIgnoreRepo::upsert_query(&users[..1], Dialect::MySql).sql(),
+        "INSERT INTO users (id, email, name, created_by) VALUES (?, ?, ?, ?) ON DUPLICATE KEY UPDATE id = id"
+    );
+}